@@ -0,0 +1,235 @@
+use js_sys::{Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+const HEADER_LENGTH: usize = 32;
+const FIELD_DESCRIPTOR_LENGTH: usize = 32;
+const DELETED_FLAG: u8 = 0x2A;
+
+// One entry from the field descriptor array: name, dBASE type letter
+// (`C`/`N`/`F`/`D`/`L`), and the byte width needed to slice each record's
+// fixed-width fields. The decimal count is not read.
+struct FieldDescriptor {
+    name: String,
+    field_type: u8,
+    length: usize,
+}
+
+fn read_header(buffer: &[u8]) -> Result<(usize, usize, usize), JsError> {
+    if buffer.len() < HEADER_LENGTH {
+        return Err(JsError::new(&format!(
+            "Invalid DBF: buffer too small for header (got {}, need {})",
+            buffer.len(),
+            HEADER_LENGTH
+        )));
+    }
+
+    let record_count = u32::from_le_bytes(buffer[4..8].try_into().unwrap()) as usize;
+    let header_length = u16::from_le_bytes(buffer[8..10].try_into().unwrap()) as usize;
+    let record_length = u16::from_le_bytes(buffer[10..12].try_into().unwrap()) as usize;
+
+    if header_length < HEADER_LENGTH || header_length > buffer.len() {
+        return Err(JsError::new(&format!(
+            "Invalid DBF: implausible header length ({})",
+            header_length
+        )));
+    }
+    if record_length == 0 {
+        return Err(JsError::new("Invalid DBF: record length cannot be zero"));
+    }
+
+    Ok((record_count, header_length, record_length))
+}
+
+fn read_field_descriptors(buffer: &[u8], header_length: usize) -> Result<Vec<FieldDescriptor>, JsError> {
+    let mut fields = Vec::new();
+    let mut offset = HEADER_LENGTH;
+
+    // The descriptor array is terminated by a 0x0D byte, not a fixed count.
+    while offset < header_length && buffer[offset] != 0x0D {
+        if offset + FIELD_DESCRIPTOR_LENGTH > buffer.len() {
+            return Err(JsError::new("Invalid DBF: truncated field descriptor"));
+        }
+
+        let name_bytes = &buffer[offset..offset + 11];
+        let name_end = name_bytes.iter().position(|&b| b == 0).unwrap_or(11);
+        let name = String::from_utf8_lossy(&name_bytes[..name_end]).into_owned();
+
+        let field_type = buffer[offset + 11];
+        let length = buffer[offset + 16] as usize;
+
+        fields.push(FieldDescriptor { name, field_type, length });
+        offset += FIELD_DESCRIPTOR_LENGTH;
+    }
+
+    Ok(fields)
+}
+
+// Checks that the field descriptors actually account for `record_length`
+// bytes (plus the leading deletion flag byte), so a corrupt or hand-edited
+// header can't send record-reading past the end of a record into the next
+// one (or off the end of the buffer).
+fn validate_record_layout(fields: &[FieldDescriptor], record_length: usize) -> Result<(), JsError> {
+    let fields_width: usize = fields.iter().map(|f| f.length).sum();
+    if fields_width + 1 != record_length {
+        return Err(JsError::new(&format!(
+            "Invalid DBF: field widths ({} bytes + 1 deletion flag) don't match record length ({})",
+            fields_width, record_length
+        )));
+    }
+    Ok(())
+}
+
+fn set(obj: &Object, key: &str, value: &JsValue) -> Result<(), JsError> {
+    Reflect::set(obj, &JsValue::from_str(key), value)
+        .map(|_| ())
+        .map_err(|_| JsError::new(&format!("Failed to set '{}' on JS object", key)))
+}
+
+// Coerces one field's raw fixed-width bytes into the JSON value the dBASE
+// type implies. Blank `N`/`F`/`D` fields and blank or `?` `L` fields become
+// `null` rather than a number, a malformed date, or `false`, so callers can
+// tell "absent" from an actual value. A blank `C` field becomes an empty string.
+fn coerce_field(field: &FieldDescriptor, raw: &[u8]) -> JsValue {
+    let text = String::from_utf8_lossy(raw);
+    let trimmed = text.trim();
+
+    match field.field_type {
+        b'N' | b'F' => {
+            if trimmed.is_empty() {
+                JsValue::NULL
+            } else {
+                trimmed.parse::<f64>().map(JsValue::from_f64).unwrap_or(JsValue::NULL)
+            }
+        }
+        b'L' => match trimmed {
+            "Y" | "y" | "T" | "t" => JsValue::from_bool(true),
+            "N" | "n" | "F" | "f" => JsValue::from_bool(false),
+            _ => JsValue::NULL, // '?' or blank: not yet set
+        },
+        b'D' => {
+            if trimmed.len() == 8 {
+                JsValue::from_str(&format!("{}-{}-{}", &trimmed[0..4], &trimmed[4..6], &trimmed[6..8]))
+            } else {
+                JsValue::NULL
+            }
+        }
+        _ => JsValue::from_str(trimmed), // 'C' and anything else: trimmed string
+    }
+}
+
+// Parses a dBASE III/IV `.dbf` buffer into a JSON array of attribute objects,
+// one slot per on-disk record (including deleted ones, as `null`), keyed by
+// field name. Keeping one slot per record — rather than compacting deleted
+// records away — means index `i` always means "record `i`" for callers like
+// `parser::parse_with_attributes` that zip this against another per-record
+// source by raw index.
+#[wasm_bindgen]
+pub fn parse_dbf(buffer: &[u8]) -> Result<JsValue, JsError> {
+    let (record_count, header_length, record_length) = read_header(buffer)?;
+    let fields = read_field_descriptors(buffer, header_length)?;
+    validate_record_layout(&fields, record_length)?;
+
+    let records = js_sys::Array::new();
+    let mut offset = header_length;
+
+    for _ in 0..record_count {
+        if offset + record_length > buffer.len() {
+            break; // fewer records on disk than the header claims: stop rather than panic
+        }
+
+        let record = &buffer[offset..offset + record_length];
+        if record[0] == DELETED_FLAG {
+            records.push(&JsValue::NULL);
+        } else {
+            let attributes = Object::new();
+            let mut field_offset = 1; // skip the deletion flag byte
+            for field in &fields {
+                let raw = &record[field_offset..field_offset + field.length];
+                set(&attributes, &field.name, &coerce_field(field, raw))?;
+                field_offset += field.length;
+            }
+            records.push(&attributes.into());
+        }
+
+        offset += record_length;
+    }
+
+    Ok(records.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn build_buffer(rows: &[(bool, &str, &str)]) -> Vec<u8> {
+        // One `C` field "NAME" (len 4) and one `N` field "AGE" (len 3).
+        let header_length = HEADER_LENGTH + FIELD_DESCRIPTOR_LENGTH * 2 + 1;
+        let record_length = 1 + 4 + 3;
+
+        let mut buf = vec![0u8; HEADER_LENGTH];
+        buf[4..8].copy_from_slice(&(rows.len() as u32).to_le_bytes());
+        buf[8..10].copy_from_slice(&(header_length as u16).to_le_bytes());
+        buf[10..12].copy_from_slice(&(record_length as u16).to_le_bytes());
+
+        let mut name_field = vec![0u8; FIELD_DESCRIPTOR_LENGTH];
+        name_field[0..4].copy_from_slice(b"NAME");
+        name_field[11] = b'C';
+        name_field[16] = 4;
+        buf.extend_from_slice(&name_field);
+
+        let mut age_field = vec![0u8; FIELD_DESCRIPTOR_LENGTH];
+        age_field[0..3].copy_from_slice(b"AGE");
+        age_field[11] = b'N';
+        age_field[16] = 3;
+        buf.extend_from_slice(&age_field);
+
+        buf.push(0x0D); // field descriptor terminator
+
+        for (deleted, name, age) in rows {
+            buf.push(if *deleted { DELETED_FLAG } else { b' ' });
+            buf.extend_from_slice(format!("{:<4}", name).as_bytes());
+            buf.extend_from_slice(format!("{:>3}", age).as_bytes());
+        }
+
+        buf
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_dbf_nulls_out_deleted_records() {
+        let buf = build_buffer(&[(false, "Bob", "42"), (true, "Ann", "30"), (false, "Cid", "21")]);
+        let result = parse_dbf(&buf).unwrap();
+        let array: js_sys::Array = result.into();
+        // One slot per on-disk record, so index 2 is still "record 2", not
+        // the next non-deleted row shifted down.
+        assert_eq!(array.length(), 3);
+        assert!(array.get(1).is_null());
+        let row = Object::from(array.get(2));
+        let age = Reflect::get(&row, &JsValue::from_str("AGE")).unwrap();
+        assert_eq!(age.as_f64(), Some(21.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_dbf_coerces_numeric_field() {
+        let buf = build_buffer(&[(false, "Bob", "42")]);
+        let result = parse_dbf(&buf).unwrap();
+        let array: js_sys::Array = result.into();
+        let row = Object::from(array.get(0));
+        let age = Reflect::get(&row, &JsValue::from_str("AGE")).unwrap();
+        assert_eq!(age.as_f64(), Some(42.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_dbf_rejects_short_buffer() {
+        assert!(parse_dbf(&[0u8; 5]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_dbf_rejects_record_length_mismatch() {
+        let mut buf = build_buffer(&[(false, "Bob", "42")]);
+        // Widen the declared record length beyond what the field descriptors
+        // (and the actual record bytes) account for.
+        buf[10..12].copy_from_slice(&99u16.to_le_bytes());
+        assert!(parse_dbf(&buf).is_err());
+    }
+}
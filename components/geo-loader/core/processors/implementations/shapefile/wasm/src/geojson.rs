@@ -49,6 +49,16 @@ impl Point {
             coordinates: vec![x, y],
         }
     }
+
+    // Accepts whatever ordinates the caller already assembled ([x, y],
+    // [x, y, z], or [x, y, z, m]), for the Z/M-aware converters that can't
+    // express a third or fourth ordinate through `new`.
+    pub fn from_coordinates(coordinates: Vec<f64>) -> Self {
+        Point {
+            type_name: "Point".to_string(),
+            coordinates,
+        }
+    }
 }
 
 impl MultiPoint {
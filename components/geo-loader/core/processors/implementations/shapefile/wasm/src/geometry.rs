@@ -2,6 +2,10 @@ use geo_types::{Coord, LineString, MultiLineString, MultiPoint, MultiPolygon, Po
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
+use crate::geojson;
+use crate::processor::{self, GeomProcessor, PairCollector};
+use crate::validation;
+
 #[derive(Serialize, Deserialize)]
 pub struct Bounds {
     min_x: f64,
@@ -21,30 +25,44 @@ impl Default for Bounds {
     }
 }
 
-// Calculate bounds for an array of coordinates
-pub fn calculate_bounds(coordinates: &[f64]) -> Result<Vec<f64>, JsError> {
-    if coordinates.len() % 2 != 0 {
-        return Err(JsError::new("Coordinates array must have even length"));
-    }
+// Accumulates an envelope over a geometry walk without materializing its
+// coordinates, unlike the writers below which need the full coordinate list.
+#[derive(Default)]
+pub struct BoundsCollector {
+    bounds: Bounds,
+}
 
-    let mut bounds = Bounds::default();
+impl BoundsCollector {
+    pub fn new() -> Self {
+        BoundsCollector::default()
+    }
 
-    for chunk in coordinates.chunks(2) {
-        let x = chunk[0];
-        let y = chunk[1];
-        bounds.min_x = bounds.min_x.min(x);
-        bounds.min_y = bounds.min_y.min(y);
-        bounds.max_x = bounds.max_x.max(x);
-        bounds.max_y = bounds.max_y.max(y);
+    pub fn finish(self) -> Vec<f64> {
+        if !self.bounds.min_x.is_finite() {
+            vec![0.0, 0.0, 0.0, 0.0]
+        } else {
+            vec![self.bounds.min_x, self.bounds.min_y, self.bounds.max_x, self.bounds.max_y]
+        }
     }
+}
 
-    if !bounds.min_x.is_finite() {
-        Ok(vec![0.0, 0.0, 0.0, 0.0])
-    } else {
-        Ok(vec![bounds.min_x, bounds.min_y, bounds.max_x, bounds.max_y])
+impl GeomProcessor for BoundsCollector {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<(), JsError> {
+        self.bounds.min_x = self.bounds.min_x.min(x);
+        self.bounds.min_y = self.bounds.min_y.min(y);
+        self.bounds.max_x = self.bounds.max_x.max(x);
+        self.bounds.max_y = self.bounds.max_y.max(y);
+        Ok(())
     }
 }
 
+// Calculate bounds for an array of coordinates
+pub fn calculate_bounds(coordinates: &[f64]) -> Result<Vec<f64>, JsError> {
+    let mut collector = BoundsCollector::new();
+    processor::walk_multi_point(coordinates, &mut collector)?;
+    Ok(collector.finish())
+}
+
 // Check if a ring is clockwise
 #[wasm_bindgen]
 pub fn is_clockwise(coordinates: &[f64]) -> Result<bool, JsError> {
@@ -78,60 +96,56 @@ pub fn convert_point(x: f64, y: f64) -> Result<JsValue, JsError> {
 // Convert array of points to MultiPoint
 #[wasm_bindgen]
 pub fn convert_multi_point(coordinates: &[f64]) -> Result<JsValue, JsError> {
-    if coordinates.len() % 2 != 0 {
-        return Err(JsError::new("Coordinates array must have even length"));
-    }
+    let mut collector = PairCollector::new();
+    processor::walk_multi_point(coordinates, &mut collector)?;
 
-    let points: Vec<Point<f64>> = coordinates
-        .chunks(2)
-        .map(|chunk| Point::new(chunk[0], chunk[1]))
+    let points: Vec<Point<f64>> = collector
+        .into_pairs()
+        .into_iter()
+        .map(|[x, y]| Point::new(x, y))
         .collect();
 
     let multi_point = MultiPoint(points);
     serde_wasm_bindgen::to_value(&multi_point).map_err(|e| JsError::new(&e.to_string()))
 }
 
-// Convert array of line coordinates to LineString or MultiLineString
+// Convert a flat coordinate buffer, split into parts by `ring_sizes`, into a
+// LineString (one part) or MultiLineString (more than one) — one part per
+// shapefile `Parts` entry, unlike `convert_polygon`'s winding-based grouping,
+// since PolyLine parts have no interior/exterior distinction to group by.
 #[wasm_bindgen]
-pub fn convert_polyline(coordinates: &[f64]) -> Result<JsValue, JsError> {
-    if coordinates.len() % 2 != 0 {
-        return Err(JsError::new("Coordinates array must have even length"));
+pub fn convert_polyline(coordinates: &[f64], ring_sizes: &[usize]) -> Result<JsValue, JsError> {
+    let parts = processor::ring_slices(coordinates, ring_sizes)?;
+    let mut collector = PairCollector::new();
+    for part in &parts {
+        processor::walk_linestring(part, false, &mut collector)?;
     }
 
-    let points: Vec<Coord<f64>> = coordinates
-        .chunks(2)
-        .map(|chunk| Coord {
-            x: chunk[0],
-            y: chunk[1],
-        })
+    let lines: Vec<LineString<f64>> = collector
+        .into_rings()
+        .into_iter()
+        .map(|pairs| LineString(pairs.into_iter().map(|[x, y]| Coord { x, y }).collect()))
         .collect();
 
-    let line_string = LineString(points);
-    serde_wasm_bindgen::to_value(&line_string).map_err(|e| JsError::new(&e.to_string()))
+    if lines.len() == 1 {
+        serde_wasm_bindgen::to_value(&lines[0]).map_err(|e| JsError::new(&e.to_string()))
+    } else {
+        let multi_line_string = MultiLineString(lines);
+        serde_wasm_bindgen::to_value(&multi_line_string).map_err(|e| JsError::new(&e.to_string()))
+    }
 }
 
 // Convert array of polygon rings to Polygon or MultiPolygon
 #[wasm_bindgen]
 pub fn convert_polygon(coordinates: &[f64], ring_sizes: &[usize]) -> Result<JsValue, JsError> {
-    if coordinates.len() % 2 != 0 {
-        return Err(JsError::new("Coordinates array must have even length"));
-    }
-
-    let mut rings: Vec<LineString<f64>> = Vec::new();
-    let mut offset = 0;
+    let mut collector = PairCollector::new();
+    processor::walk_polygon(coordinates, ring_sizes, false, 0, &mut collector)?;
 
-    for &size in ring_sizes {
-        let ring_coords = &coordinates[offset..offset + size * 2];
-        let points: Vec<Coord<f64>> = ring_coords
-            .chunks(2)
-            .map(|chunk| Coord {
-                x: chunk[0],
-                y: chunk[1],
-            })
-            .collect();
-        rings.push(LineString(points));
-        offset += size * 2;
-    }
+    let rings: Vec<LineString<f64>> = collector
+        .into_rings()
+        .into_iter()
+        .map(|pairs| LineString(pairs.into_iter().map(|[x, y]| Coord { x, y }).collect()))
+        .collect();
 
     let mut polygons: Vec<Polygon<f64>> = Vec::new();
     let mut current_polygon = Vec::new();
@@ -165,6 +179,629 @@ pub fn convert_polygon(coordinates: &[f64], ring_sizes: &[usize]) -> Result<JsVa
     }
 }
 
+// Appends whichever of Z/M are present to a 2D coordinate pair. M is dropped
+// when non-finite (NaN means "no measure" by this crate's own convention).
+fn push_ordinates(coord: &mut Vec<f64>, z: Option<f64>, m: Option<f64>) {
+    if let Some(z) = z {
+        coord.push(z);
+    }
+    if let Some(m) = m {
+        if m.is_finite() {
+            coord.push(m);
+        }
+    }
+}
+
+// Range-checks every point's Z/M ordinates (when present) alongside its x/y,
+// and checks the optional arrays are sized to match `coordinates`.
+fn validate_ordinates(coordinates: &[f64], z: Option<&[f64]>, m: Option<&[f64]>) -> Result<(), JsError> {
+    let count = coordinates.len() / 2;
+    if let Some(z) = z {
+        if z.len() != count {
+            return Err(JsError::new("Z array length must match the number of points"));
+        }
+    }
+    if let Some(m) = m {
+        if m.len() != count {
+            return Err(JsError::new("M array length must match the number of points"));
+        }
+    }
+
+    for i in 0..count {
+        let x = coordinates[i * 2];
+        let y = coordinates[i * 2 + 1];
+        match z {
+            Some(z) => validation::validate_point_coordinates_z(
+                x,
+                y,
+                z[i],
+                m.map(|m| m[i]).unwrap_or(f64::NAN),
+                0,
+                i as i32,
+            )?,
+            None => {
+                validation::validate_point_coordinates(x, y, 0, i as i32)?;
+                if let Some(m) = m.filter(|m| m[i].is_infinite()) {
+                    return Err(JsError::new(&format!(
+                        "Invalid shapefile: invalid M value ({}) at part 0, point {}",
+                        m[i], i
+                    )));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// Convert a PointZ/PointM to GeoJSON, with coordinates `[x, y]`, `[x, y, z]`,
+// `[x, y, m]`, or `[x, y, z, m]` depending on which of `z`/`m` are present.
+pub fn convert_point_z(x: f64, y: f64, z: Option<f64>, m: Option<f64>) -> Result<JsValue, JsError> {
+    match z {
+        Some(z) => validation::validate_point_coordinates_z(x, y, z, m.unwrap_or(f64::NAN), 0, 0)?,
+        None => validation::validate_point_coordinates(x, y, 0, 0)?,
+    }
+
+    let mut coords = vec![x, y];
+    push_ordinates(&mut coords, z, m);
+    let point = geojson::Point::from_coordinates(coords);
+    serde_wasm_bindgen::to_value(&point).map_err(|e| JsError::new(&e.to_string()))
+}
+
+// Convert a MultiPointZ/MultiPointM to GeoJSON.
+pub fn convert_multi_point_z(coordinates: &[f64], z: Option<&[f64]>, m: Option<&[f64]>) -> Result<JsValue, JsError> {
+    validate_ordinates(coordinates, z, m)?;
+
+    let mut collector = PairCollector::new();
+    processor::walk_multi_point(coordinates, &mut collector)?;
+
+    let points: Vec<Vec<f64>> = collector
+        .into_pairs()
+        .into_iter()
+        .enumerate()
+        .map(|(i, [x, y])| {
+            let mut coord = vec![x, y];
+            push_ordinates(&mut coord, z.map(|z| z[i]), m.map(|m| m[i]));
+            coord
+        })
+        .collect();
+
+    let multi_point = geojson::MultiPoint::new(points);
+    serde_wasm_bindgen::to_value(&multi_point).map_err(|e| JsError::new(&e.to_string()))
+}
+
+// Convert a PolyLineZ/PolyLineM to GeoJSON. Like `convert_polyline`, splits
+// the buffer into parts by `ring_sizes`, emitting a LineString for one part
+// or a MultiLineString for more than one.
+pub fn convert_polyline_z(
+    coordinates: &[f64],
+    ring_sizes: &[usize],
+    z: Option<&[f64]>,
+    m: Option<&[f64]>,
+) -> Result<JsValue, JsError> {
+    validate_ordinates(coordinates, z, m)?;
+
+    let parts = processor::ring_slices(coordinates, ring_sizes)?;
+    let mut collector = PairCollector::new();
+    for part in &parts {
+        processor::walk_linestring(part, false, &mut collector)?;
+    }
+
+    let mut idx = 0usize;
+    let mut lines: Vec<Vec<Vec<f64>>> = collector
+        .into_rings()
+        .into_iter()
+        .map(|pairs| {
+            pairs
+                .into_iter()
+                .map(|[x, y]| {
+                    let mut coord = vec![x, y];
+                    push_ordinates(&mut coord, z.map(|z| z[idx]), m.map(|m| m[idx]));
+                    idx += 1;
+                    coord
+                })
+                .collect()
+        })
+        .collect();
+
+    if lines.len() == 1 {
+        let line_string = geojson::LineString::new(lines.remove(0));
+        serde_wasm_bindgen::to_value(&line_string).map_err(|e| JsError::new(&e.to_string()))
+    } else {
+        let multi_line_string = geojson::MultiLineString::new(lines);
+        serde_wasm_bindgen::to_value(&multi_line_string).map_err(|e| JsError::new(&e.to_string()))
+    }
+}
+
+// Convert a PolygonZ/PolygonM to GeoJSON. Unlike `convert_polygon`, this
+// doesn't regroup rings into a MultiPolygon by winding — the Z/M shape types
+// carry the same ring layout as their 2D counterparts, so that refinement is
+// left to the 2D path.
+pub fn convert_polygon_z(
+    coordinates: &[f64],
+    ring_sizes: &[usize],
+    z: Option<&[f64]>,
+    m: Option<&[f64]>,
+) -> Result<JsValue, JsError> {
+    validate_ordinates(coordinates, z, m)?;
+
+    let mut collector = PairCollector::new();
+    processor::walk_polygon(coordinates, ring_sizes, false, 0, &mut collector)?;
+
+    let mut idx = 0usize;
+    let rings: Vec<Vec<Vec<f64>>> = collector
+        .into_rings()
+        .into_iter()
+        .map(|pairs| {
+            pairs
+                .into_iter()
+                .map(|[x, y]| {
+                    let mut coord = vec![x, y];
+                    push_ordinates(&mut coord, z.map(|z| z[idx]), m.map(|m| m[idx]));
+                    idx += 1;
+                    coord
+                })
+                .collect()
+        })
+        .collect();
+
+    let polygon = geojson::Polygon::new(rings);
+    serde_wasm_bindgen::to_value(&polygon).map_err(|e| JsError::new(&e.to_string()))
+}
+
+// MultiPatch part-type codes from the shapefile spec's `PartType` enum.
+// Ring-ish parts are grouped into polygons the same way 2D rings are;
+// triangle strips/fans have no GeoJSON equivalent, so they're fanned out
+// into individual 3-point polygons instead.
+const PART_TYPE_TRIANGLE_STRIP: u32 = 0;
+const PART_TYPE_TRIANGLE_FAN: u32 = 1;
+const PART_TYPE_OUTER_RING: u32 = 2;
+const PART_TYPE_INNER_RING: u32 = 3;
+const PART_TYPE_FIRST_RING: u32 = 4;
+const PART_TYPE_RING: u32 = 5;
+
+fn close_ring(mut points: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    if points.first() != points.last() {
+        if let Some(first) = points.first().cloned() {
+            points.push(first);
+        }
+    }
+    points
+}
+
+// Fans a TriangleStrip part's `n` points into `n - 2` individual triangle
+// polygons, alternating winding every other triangle the way the shapefile
+// spec's strip vertices do, so each emitted triangle keeps a consistent face.
+fn triangle_strip_to_polygons(points: &[Vec<f64>]) -> Vec<Vec<Vec<Vec<f64>>>> {
+    (0..points.len().saturating_sub(2))
+        .map(|i| {
+            let tri = if i % 2 == 0 {
+                vec![points[i].clone(), points[i + 1].clone(), points[i + 2].clone()]
+            } else {
+                vec![points[i + 1].clone(), points[i].clone(), points[i + 2].clone()]
+            };
+            vec![close_ring(tri)]
+        })
+        .collect()
+}
+
+// Fans a TriangleFan part's `n` points (point 0 is the shared apex) into
+// `n - 2` individual triangle polygons.
+fn triangle_fan_to_polygons(points: &[Vec<f64>]) -> Vec<Vec<Vec<Vec<f64>>>> {
+    let Some(apex) = points.first() else {
+        return Vec::new();
+    };
+    (1..points.len().saturating_sub(1))
+        .map(|i| vec![close_ring(vec![apex.clone(), points[i].clone(), points[i + 1].clone()])])
+        .collect()
+}
+
+// Convert a MultiPatch to GeoJSON. `ring_sizes`/`part_types` mirror the
+// shapefile spec's Parts/PartTypes arrays: OuterRing/FirstRing parts start a
+// new polygon, InnerRing/Ring parts add a hole to the current one, and
+// TriangleStrip/TriangleFan parts are each fanned out into their own 3-point
+// polygons, since GeoJSON has no native triangle-mesh geometry. `z` is not
+// required: without it the output coordinates are 2D. M is optional, as with
+// the other Z/M converters.
+pub fn convert_multipatch(
+    coordinates: &[f64],
+    ring_sizes: &[usize],
+    part_types: &[u32],
+    z: Option<&[f64]>,
+    m: Option<&[f64]>,
+) -> Result<JsValue, JsError> {
+    if ring_sizes.len() != part_types.len() {
+        return Err(JsError::new("Part type array length must match the number of parts"));
+    }
+    validate_ordinates(coordinates, z, m)?;
+
+    let mut polygons: Vec<Vec<Vec<Vec<f64>>>> = Vec::new();
+    let mut current_rings: Vec<Vec<Vec<f64>>> = Vec::new();
+    let mut offset = 0usize;
+
+    for (&size, &part_type) in ring_sizes.iter().zip(part_types) {
+        let part_coords = &coordinates[offset * 2..(offset + size) * 2];
+        let points: Vec<Vec<f64>> = part_coords
+            .chunks(2)
+            .enumerate()
+            .map(|(i, c)| {
+                let idx = offset + i;
+                let mut coord = vec![c[0], c[1]];
+                push_ordinates(&mut coord, z.map(|z| z[idx]), m.map(|m| m[idx]));
+                coord
+            })
+            .collect();
+
+        match part_type {
+            PART_TYPE_OUTER_RING | PART_TYPE_FIRST_RING => {
+                if !current_rings.is_empty() {
+                    polygons.push(std::mem::take(&mut current_rings));
+                }
+                current_rings.push(points);
+            }
+            PART_TYPE_INNER_RING | PART_TYPE_RING => current_rings.push(points),
+            PART_TYPE_TRIANGLE_STRIP => {
+                if !current_rings.is_empty() {
+                    polygons.push(std::mem::take(&mut current_rings));
+                }
+                polygons.extend(triangle_strip_to_polygons(&points));
+            }
+            PART_TYPE_TRIANGLE_FAN => {
+                if !current_rings.is_empty() {
+                    polygons.push(std::mem::take(&mut current_rings));
+                }
+                polygons.extend(triangle_fan_to_polygons(&points));
+            }
+            _ => return Err(JsError::new("Unsupported MultiPatch part type")),
+        }
+
+        offset += size;
+    }
+    if !current_rings.is_empty() {
+        polygons.push(current_rings);
+    }
+
+    let multi_patch = geojson::MultiPolygon::new(polygons);
+    serde_wasm_bindgen::to_value(&multi_patch).map_err(|e| JsError::new(&e.to_string()))
+}
+
+// A vertex in the earcut working list: an index into the output `vertices`
+// buffer plus doubly-linked neighbors so ears can be clipped and holes
+// spliced in without shifting the rest of the ring.
+#[derive(Clone, Copy)]
+struct EarNode {
+    i: usize,
+    x: f64,
+    y: f64,
+    prev: usize,
+    next: usize,
+}
+
+fn cross(ax: f64, ay: f64, bx: f64, by: f64, cx: f64, cy: f64) -> f64 {
+    (bx - ax) * (cy - ay) - (cx - ax) * (by - ay)
+}
+
+fn ensure_ring_winding(points: &mut [(f64, f64)], want_clockwise: bool) -> Result<(), JsError> {
+    let flat: Vec<f64> = points.iter().flat_map(|&(x, y)| vec![x, y]).collect();
+    if is_clockwise(&flat)? != want_clockwise {
+        points.reverse();
+    }
+    Ok(())
+}
+
+// Drops vertices that are collinear with both neighbors, which would
+// otherwise produce zero-area "ears" the clipper can't resolve.
+fn remove_collinear_points(points: &mut Vec<(f64, f64)>) {
+    loop {
+        let n = points.len();
+        if n < 3 {
+            return;
+        }
+        let mut changed = false;
+        let mut i = 0;
+        while i < points.len() && points.len() >= 3 {
+            let n = points.len();
+            let (ax, ay) = points[(i + n - 1) % n];
+            let (bx, by) = points[i];
+            let (cx, cy) = points[(i + 1) % n];
+            if cross(ax, ay, bx, by, cx, cy) == 0.0 {
+                points.remove(i);
+                changed = true;
+            } else {
+                i += 1;
+            }
+        }
+        if !changed {
+            return;
+        }
+    }
+}
+
+fn insert_ear_node(nodes: &mut Vec<EarNode>, i: usize, x: f64, y: f64, last: Option<usize>) -> usize {
+    let idx = nodes.len();
+    let node = match last {
+        None => EarNode {
+            i,
+            x,
+            y,
+            prev: idx,
+            next: idx,
+        },
+        Some(last_idx) => EarNode {
+            i,
+            x,
+            y,
+            prev: last_idx,
+            next: nodes[last_idx].next,
+        },
+    };
+    nodes.push(node);
+    if let Some(last_idx) = last {
+        let next_idx = nodes[idx].next;
+        nodes[last_idx].next = idx;
+        nodes[next_idx].prev = idx;
+    }
+    idx
+}
+
+// Builds a circular linked list for one ring, appending its (deduplicated)
+// points to the shared output `vertices` buffer, and returns the index of
+// the last node inserted, or `None` if the ring collapsed entirely.
+fn build_ear_ring(points: &[(f64, f64)], vertices: &mut Vec<f64>, nodes: &mut Vec<EarNode>) -> Option<usize> {
+    let mut last: Option<usize> = None;
+    for &(x, y) in points {
+        if let Some(last_idx) = last {
+            if nodes[last_idx].x == x && nodes[last_idx].y == y {
+                continue; // skip points that don't move from the previous one
+            }
+        }
+        let i = vertices.len() / 2;
+        vertices.push(x);
+        vertices.push(y);
+        last = Some(insert_ear_node(nodes, i, x, y, last));
+    }
+
+    // The ring is implicitly closed; drop an explicit closing duplicate of the first point.
+    if let Some(last_idx) = last {
+        let first_idx = nodes[last_idx].next;
+        if first_idx != last_idx && nodes[first_idx].x == nodes[last_idx].x && nodes[first_idx].y == nodes[last_idx].y {
+            let new_first = nodes[first_idx].next;
+            nodes[last_idx].next = new_first;
+            nodes[new_first].prev = last_idx;
+        }
+    }
+
+    last
+}
+
+struct Triangle {
+    ax: f64,
+    ay: f64,
+    bx: f64,
+    by: f64,
+    cx: f64,
+    cy: f64,
+}
+
+fn point_in_triangle(t: &Triangle, px: f64, py: f64) -> bool {
+    let d1 = cross(t.ax, t.ay, t.bx, t.by, px, py);
+    let d2 = cross(t.bx, t.by, t.cx, t.cy, px, py);
+    let d3 = cross(t.cx, t.cy, t.ax, t.ay, px, py);
+    let has_neg = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_pos = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_neg && has_pos)
+}
+
+// A node is an ear when its corner is convex (for the CCW-wound working
+// ring) and no other remaining *reflex* vertex falls inside the triangle it
+// cuts off. Only reflex vertices can ever sit inside a convex ear, so convex
+// ones are skipped; this also keeps the hole-bridging duplicate nodes (which
+// coincide exactly with a real vertex) from falsely "blocking" an ear just
+// because they sit on its boundary.
+fn is_ear(nodes: &[EarNode], ear: usize) -> bool {
+    let a = nodes[ear].prev;
+    let c = nodes[ear].next;
+    let (ax, ay) = (nodes[a].x, nodes[a].y);
+    let (bx, by) = (nodes[ear].x, nodes[ear].y);
+    let (cx, cy) = (nodes[c].x, nodes[c].y);
+
+    if cross(ax, ay, bx, by, cx, cy) <= 0.0 {
+        return false; // reflex or collinear corner
+    }
+    let triangle = Triangle { ax, ay, bx, by, cx, cy };
+
+    let mut p = nodes[c].next;
+    while p != a {
+        let (px, py) = (nodes[p].x, nodes[p].y);
+        let (pp, pn) = (nodes[p].prev, nodes[p].next);
+        let p_is_reflex = cross(nodes[pp].x, nodes[pp].y, px, py, nodes[pn].x, nodes[pn].y) <= 0.0;
+        if p_is_reflex && !(px == ax && py == ay) && point_in_triangle(&triangle, px, py) {
+            return false;
+        }
+        p = nodes[p].next;
+    }
+    true
+}
+
+// Finds a vertex on the outer ring mutually visible from the hole's entry
+// vertex (the last vertex `build_ear_ring` inserted for it), so the hole can be
+// bridged into one simple ring. Only the outer ring's edges are tested for
+// crossings, and the nearest such clear vertex wins.
+fn find_hole_bridge(nodes: &[EarNode], hole: usize, outer_start: usize) -> usize {
+    let (hx, hy) = (nodes[hole].x, nodes[hole].y);
+    let mut best = outer_start;
+    let mut best_dist = f64::INFINITY;
+
+    let mut p = outer_start;
+    loop {
+        let dx = nodes[p].x - hx;
+        let dy = nodes[p].y - hy;
+        let dist = dx * dx + dy * dy;
+        if dist < best_dist && segment_is_clear(nodes, hole, p, outer_start) {
+            best = p;
+            best_dist = dist;
+        }
+        p = nodes[p].next;
+        if p == outer_start {
+            break;
+        }
+    }
+    best
+}
+
+// Whether the segment from `hole` to `candidate` crosses any edge of the
+// ring starting at `outer_start` (other than the two edges touching `candidate`).
+fn segment_is_clear(nodes: &[EarNode], hole: usize, candidate: usize, outer_start: usize) -> bool {
+    let (hx, hy) = (nodes[hole].x, nodes[hole].y);
+    let (qx, qy) = (nodes[candidate].x, nodes[candidate].y);
+
+    let mut p = outer_start;
+    loop {
+        let next = nodes[p].next;
+        if p != candidate && next != candidate {
+            let p1 = (nodes[p].x, nodes[p].y);
+            let p2 = (nodes[next].x, nodes[next].y);
+            if segments_intersect((hx, hy), (qx, qy), p1, p2) {
+                return false;
+            }
+        }
+        p = next;
+        if p == outer_start {
+            break;
+        }
+    }
+    true
+}
+
+fn segments_intersect(a: (f64, f64), b: (f64, f64), c: (f64, f64), d: (f64, f64)) -> bool {
+    let (ax, ay) = a;
+    let (bx, by) = b;
+    let (cx, cy) = c;
+    let (dx, dy) = d;
+    let d1 = cross(cx, cy, dx, dy, ax, ay);
+    let d2 = cross(cx, cy, dx, dy, bx, by);
+    let d3 = cross(ax, ay, bx, by, cx, cy);
+    let d4 = cross(ax, ay, bx, by, dx, dy);
+    ((d1 > 0.0) != (d2 > 0.0)) && ((d3 > 0.0) != (d4 > 0.0))
+}
+
+// Splices a hole ring into the outer ring by duplicating the bridge and hole
+// entry vertices, so the two loops become one simple ring (mapbox/earcut's
+// `splitPolygon` trick). Returns the new entry point into the combined ring.
+fn eliminate_hole(nodes: &mut Vec<EarNode>, hole: usize, outer_start: usize) -> usize {
+    let bridge = find_hole_bridge(nodes, hole, outer_start);
+
+    let a2 = nodes.len();
+    nodes.push(nodes[bridge]);
+    let b2 = nodes.len();
+    nodes.push(nodes[hole]);
+
+    let an = nodes[bridge].next;
+    let bp = nodes[hole].prev;
+
+    nodes[bridge].next = hole;
+    nodes[hole].prev = bridge;
+
+    nodes[a2].next = an;
+    nodes[an].prev = a2;
+
+    nodes[b2].next = a2;
+    nodes[a2].prev = b2;
+
+    nodes[bp].next = b2;
+    nodes[b2].prev = bp;
+
+    bridge
+}
+
+// Repeatedly clips convex, empty-triangle "ears" off the working ring until
+// only a triangle remains. Falls back to clipping the current vertex
+// regardless of reflex/containment if no ear is found after a full sweep,
+// which keeps self-touching or otherwise degenerate input from looping forever.
+fn ear_clip(nodes: &mut [EarNode], start: usize, triangles: &mut Vec<u32>) {
+    let mut ear = start;
+    let mut stalled = 0usize;
+    let safety_bound = nodes.len().max(1);
+
+    while nodes[ear].next != nodes[ear].prev {
+        let prev = nodes[ear].prev;
+        let next = nodes[ear].next;
+
+        if stalled <= safety_bound && !is_ear(nodes, ear) {
+            ear = next;
+            stalled += 1;
+            continue;
+        }
+
+        triangles.push(nodes[prev].i as u32);
+        triangles.push(nodes[ear].i as u32);
+        triangles.push(nodes[next].i as u32);
+
+        nodes[prev].next = next;
+        nodes[next].prev = prev;
+        ear = next;
+        stalled = 0;
+    }
+}
+
+// Converts a polygon-with-holes into an indexed triangle list for GPU upload,
+// via ear clipping. `ring_sizes[0]` is the exterior ring; remaining entries
+// are holes, matching the convention the rest of the crate uses.
+pub fn triangulate_polygon(coordinates: &[f64], ring_sizes: &[usize]) -> Result<(Vec<f64>, Vec<u32>), JsError> {
+    if !coordinates.len().is_multiple_of(2) {
+        return Err(JsError::new("Coordinates array must have even length"));
+    }
+    if ring_sizes.is_empty() {
+        return Err(JsError::new("Polygon must have at least one ring"));
+    }
+
+    let mut offset = 0;
+    let mut vertices: Vec<f64> = Vec::new();
+    let mut nodes: Vec<EarNode> = Vec::new();
+    let mut outer_start: Option<usize> = None;
+    let mut hole_starts: Vec<usize> = Vec::new();
+
+    for (ring_idx, &size) in ring_sizes.iter().enumerate() {
+        let end = offset + size * 2;
+        if end > coordinates.len() {
+            return Err(JsError::new("Ring size exceeds coordinate buffer"));
+        }
+        let ring = &coordinates[offset..end];
+        offset = end;
+
+        if ring.len() < 6 {
+            continue; // degenerate ring, skip
+        }
+
+        let mut points: Vec<(f64, f64)> = ring.chunks(2).map(|c| (c[0], c[1])).collect();
+        // Exterior ring is wound CCW, holes CW, per the earcut convention.
+        ensure_ring_winding(&mut points, ring_idx != 0)?;
+        remove_collinear_points(&mut points);
+        if points.len() < 3 {
+            continue; // zero-area ring, skip
+        }
+
+        if let Some(start) = build_ear_ring(&points, &mut vertices, &mut nodes) {
+            if ring_idx == 0 {
+                outer_start = Some(start);
+            } else {
+                hole_starts.push(start);
+            }
+        }
+    }
+
+    let Some(mut outer) = outer_start else {
+        return Ok((vertices, Vec::new()));
+    };
+
+    for hole in hole_starts {
+        outer = eliminate_hole(&mut nodes, hole, outer);
+    }
+
+    let mut triangles = Vec::new();
+    ear_clip(&mut nodes, outer, &mut triangles);
+    Ok((vertices, triangles))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -187,4 +824,121 @@ mod tests {
         let counter_clockwise = vec![0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0];
         assert!(!is_clockwise(&counter_clockwise).unwrap());
     }
+
+    #[wasm_bindgen_test]
+    fn test_triangulate_square() {
+        let square = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let (vertices, indices) = triangulate_polygon(&square, &[4]).unwrap();
+        assert_eq!(vertices.len(), 8);
+        assert_eq!(indices.len(), 6); // two triangles
+    }
+
+    #[wasm_bindgen_test]
+    fn test_triangulate_square_with_hole() {
+        let outer = vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0];
+        let hole = vec![3.0, 3.0, 3.0, 7.0, 7.0, 7.0, 7.0, 3.0];
+        let coords: Vec<f64> = outer.into_iter().chain(hole).collect();
+        let (vertices, indices) = triangulate_polygon(&coords, &[4, 4]).unwrap();
+        assert!(indices.len() >= 18); // at least 6 triangles once the hole is cut in
+
+        // The sum of the triangles' unsigned areas must equal the polygon's
+        // net area (100 - 16 = 84). If the mesh contains an overlapping,
+        // oppositely-wound triangle pair the unsigned sum overshoots this,
+        // double-drawing geometry under WebGL backface culling.
+        let total_area: f64 = indices
+            .chunks(3)
+            .map(|tri| {
+                let (ax, ay) = (vertices[tri[0] as usize * 2], vertices[tri[0] as usize * 2 + 1]);
+                let (bx, by) = (vertices[tri[1] as usize * 2], vertices[tri[1] as usize * 2 + 1]);
+                let (cx, cy) = (vertices[tri[2] as usize * 2], vertices[tri[2] as usize * 2 + 1]);
+                0.5 * ((bx - ax) * (cy - ay) - (cx - ax) * (by - ay)).abs()
+            })
+            .sum();
+        assert!((total_area - 84.0).abs() < 1e-9, "unexpected mesh area {total_area}, expected 84 (overlapping triangles?)");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_triangulate_rejects_empty_ring_sizes() {
+        assert!(triangulate_polygon(&[0.0, 0.0, 1.0, 0.0, 0.0, 1.0], &[]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_convert_point_z() {
+        let result = convert_point_z(1.0, 2.0, Some(10.0), Some(5.0)).unwrap();
+        assert!(result.is_object());
+
+        // Z is required to be finite even when M is absent.
+        assert!(convert_point_z(1.0, 2.0, Some(f64::NAN), None).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_convert_multi_point_z_rejects_mismatched_lengths() {
+        let coords = vec![0.0, 0.0, 1.0, 1.0];
+        assert!(convert_multi_point_z(&coords, Some(&[1.0]), None).is_err());
+        assert!(convert_multi_point_z(&coords, Some(&[1.0, 2.0]), None).is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_convert_polyline_single_part_is_line_string() {
+        let coords = vec![0.0, 0.0, 1.0, 1.0];
+        let result = convert_polyline(&coords, &[2]).unwrap();
+        let points: js_sys::Array = result.into();
+        // A LineString serializes as a flat array of points, not a nested
+        // array of parts.
+        assert_eq!(points.length(), 2);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_convert_polyline_multi_part_does_not_join_parts() {
+        // Two disjoint segments; a naive single-LineString flattening would
+        // draw a bogus joining segment from (1,1) to (10,10).
+        let coords = vec![0.0, 0.0, 1.0, 1.0, 10.0, 10.0, 11.0, 11.0];
+        let result = convert_polyline(&coords, &[2, 2]).unwrap();
+        let lines: js_sys::Array = result.into();
+        // A MultiLineString serializes as one nested array per part.
+        assert_eq!(lines.length(), 2);
+        let first_part: js_sys::Array = lines.get(0).into();
+        assert_eq!(first_part.length(), 2);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_convert_polyline_z_multi_part_does_not_join_parts() {
+        let coords = vec![0.0, 0.0, 1.0, 1.0, 10.0, 10.0, 11.0, 11.0];
+        let z = vec![1.0, 1.0, 1.0, 1.0];
+        let result = convert_polyline_z(&coords, &[2, 2], Some(&z), None).unwrap();
+        let geom_type = js_sys::Reflect::get(&result, &JsValue::from_str("type")).unwrap();
+        assert_eq!(geom_type.as_string().as_deref(), Some("MultiLineString"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_convert_polygon_z_roundtrips_ring() {
+        let square = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let z = vec![1.0, 1.0, 1.0, 1.0];
+        let result = convert_polygon_z(&square, &[4], Some(&z), None);
+        assert!(result.is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_convert_multipatch_ring_part() {
+        let square = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let z = vec![1.0, 1.0, 1.0, 1.0];
+        let result = convert_multipatch(&square, &[4], &[PART_TYPE_OUTER_RING], Some(&z), None);
+        assert!(result.is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_convert_multipatch_triangle_fan() {
+        // A 4-point fan around apex (0,0): 2 triangles.
+        let fan = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+        let z = vec![0.0, 0.0, 0.0, 0.0];
+        let result = convert_multipatch(&fan, &[4], &[PART_TYPE_TRIANGLE_FAN], Some(&z), None);
+        assert!(result.is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_convert_multipatch_rejects_mismatched_part_types() {
+        let square = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let z = vec![1.0, 1.0, 1.0, 1.0];
+        assert!(convert_multipatch(&square, &[4], &[], Some(&z), None).is_err());
+    }
 }
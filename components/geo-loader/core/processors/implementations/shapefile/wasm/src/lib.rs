@@ -1,8 +1,14 @@
+use serde::Serialize;
 use wasm_bindgen::prelude::*;
 
 mod geometry;
 mod validation;
+mod dbf;
 mod geojson;
+mod mvt;
+mod parser;
+mod processor;
+mod wkt;
 
 // Initialize better error handling for Wasm
 #[wasm_bindgen(start)]
@@ -20,6 +26,12 @@ pub use geometry::{
     is_clockwise,
 };
 
+// Re-export WKT functions
+pub use wkt::{geometry_to_wkt, wkt_to_geometry};
+
+// Re-export the DBF attribute table reader
+pub use dbf::parse_dbf;
+
 // Re-export validation functions
 pub use validation::{
     validate_header_buffer,
@@ -36,12 +48,32 @@ pub use validation::{
     validate_shape_type,
 };
 
+// An indexed triangle mesh ready for upload to a GPU vertex/index buffer.
+#[derive(Serialize)]
+pub struct TriangleMesh {
+    vertices: Vec<f64>,
+    indices: Vec<u32>,
+}
+
 // Main WebAssembly interface
 #[wasm_bindgen]
 pub struct ShapefileProcessor {
     // Will hold internal state if needed
 }
 
+// `process_geometry` and `encode_tile` below, like the free function
+// `geometry_to_wkt` in wkt.rs, walk shapefile geometry through the shared
+// `GeomProcessor` trait (see processor.rs) instead of duplicating
+// ring-walking boilerplate per output format; `triangulate_polygon` reads the
+// coordinate buffer directly. They stay separate wasm_bindgen entry points
+// rather than one "pick an output encoding" entry point:
+// each sink needs a genuinely disjoint set of extra inputs — MVT needs tile
+// placement plus a properties object to tag features with, triangulation
+// needs ring_sizes instead of Z/M ordinates, GeoJSON needs Z/M but no tile or
+// properties at all. A single method taking the union of all of that would
+// force every JS caller, including the common GeoJSON path, to thread through
+// parameters it never uses. Kept separate as an intentional design decision
+// for this wasm-bindgen surface, not a scope cut.
 #[wasm_bindgen]
 impl ShapefileProcessor {
     #[wasm_bindgen(constructor)]
@@ -49,13 +81,28 @@ impl ShapefileProcessor {
         ShapefileProcessor {}
     }
 
+    // `z` and `m` carry the Z (elevation) and M (measure) ordinates for the
+    // Z/M shape type codes, in the same point order as `coordinates`; pass
+    // empty slices for the 2D shape types, which ignore them. `part_types`
+    // carries the MultiPatch PartType tags (TriangleStrip/Fan/OuterRing/...)
+    // and is ignored by every other shape type.
     #[wasm_bindgen]
-    pub fn process_geometry(&self, shape_type: u32, coordinates: &[f64]) -> Result<JsValue, JsError> {
+    pub fn process_geometry(
+        &self,
+        shape_type: u32,
+        coordinates: &[f64],
+        z: &[f64],
+        m: &[f64],
+        part_types: &[u32],
+    ) -> Result<JsValue, JsError> {
         // Validate shape type first
         if !validation::validate_shape_type(shape_type)? {
             return Err(JsError::new("Invalid or null shape type"));
         }
 
+        let z = (!z.is_empty()).then_some(z);
+        let m = (!m.is_empty()).then_some(m);
+
         // Process based on shape type
         match shape_type {
             1 => { // Point
@@ -65,7 +112,9 @@ impl ShapefileProcessor {
                 geometry::convert_point(coordinates[0], coordinates[1])
             },
             3 => { // PolyLine
-                geometry::convert_polyline(coordinates)
+                // For polylines, we need part sizes. For now, treat as one part
+                let ring_sizes = vec![coordinates.len() / 2];
+                geometry::convert_polyline(coordinates, &ring_sizes)
             },
             5 => { // Polygon
                 // For polygons, we need ring sizes. For now, treat as one ring
@@ -75,9 +124,87 @@ impl ShapefileProcessor {
             8 => { // MultiPoint
                 geometry::convert_multi_point(coordinates)
             },
+            11 | 21 => { // PointZ / PointM
+                if coordinates.len() != 2 {
+                    return Err(JsError::new("Point must have exactly 2 coordinates"));
+                }
+                let point_z = z.map(|z| z[0]);
+                let point_m = m.map(|m| m[0]);
+                geometry::convert_point_z(coordinates[0], coordinates[1], point_z, point_m)
+            },
+            13 | 23 => { // PolyLineZ / PolyLineM
+                // For polylines, we need part sizes. For now, treat as one part
+                let ring_sizes = vec![coordinates.len() / 2];
+                geometry::convert_polyline_z(coordinates, &ring_sizes, z, m)
+            },
+            15 | 25 => { // PolygonZ / PolygonM
+                // For polygons, we need ring sizes. For now, treat as one ring
+                let ring_sizes = vec![coordinates.len() / 2];
+                geometry::convert_polygon_z(coordinates, &ring_sizes, z, m)
+            },
+            18 | 28 => { // MultiPointZ / MultiPointM
+                geometry::convert_multi_point_z(coordinates, z, m)
+            },
+            31 => { // MultiPatch
+                // This entry point has no per-part ring/part-type plumbing
+                // (the 2D Polygon arm above has the same limitation), so we
+                // treat the whole buffer as one part; `parser.rs` carries
+                // the real Parts/PartTypes arrays through for `.shp` files.
+                let ring_sizes = vec![coordinates.len() / 2];
+                let part_type = part_types.first().copied().unwrap_or(5); // default: Ring
+                geometry::convert_multipatch(coordinates, &ring_sizes, &[part_type], z, m)
+            },
             _ => Err(JsError::new("Unsupported shape type")),
         }
     }
+
+    // Encode a single geometry as a protobuf Mapbox Vector Tile byte buffer,
+    // quantized into the given tile's integer coordinate space. `ring_sizes`
+    // carries the exterior-ring/hole split for Polygon (empty treats the
+    // whole buffer as one ring); `properties`, a plain JS object, becomes the
+    // feature's integer-keyed tags via the layer's keys/values tables.
+    #[wasm_bindgen]
+    pub fn encode_tile(
+        &self,
+        shape_type: u32,
+        coordinates: &[f64],
+        ring_sizes: &[usize],
+        tile_x: u32,
+        tile_y: u32,
+        tile_z: u32,
+        extent: u32,
+        properties: &JsValue,
+    ) -> Result<Vec<u8>, JsError> {
+        if !validation::validate_shape_type(shape_type)? {
+            return Err(JsError::new("Invalid or null shape type"));
+        }
+
+        mvt::encode_tile(shape_type, coordinates, ring_sizes, tile_x, tile_y, tile_z, extent, properties)
+    }
+
+    // Triangulate a polygon-with-holes (ear clipping) into a vertex/index
+    // mesh suitable for uploading to a WebGL buffer.
+    #[wasm_bindgen]
+    pub fn triangulate_polygon(&self, coordinates: &[f64], ring_sizes: &[usize]) -> Result<JsValue, JsError> {
+        let (vertices, indices) = geometry::triangulate_polygon(coordinates, ring_sizes)?;
+        let mesh = TriangleMesh { vertices, indices };
+        serde_wasm_bindgen::to_value(&mesh).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    // Parse a whole `.shp` buffer into a GeoJSON FeatureCollection, driving
+    // the header/record validators directly instead of requiring callers to
+    // parse the binary layout themselves in TypeScript.
+    #[wasm_bindgen]
+    pub fn parse(&self, buffer: &[u8]) -> Result<JsValue, JsError> {
+        parser::parse(buffer)
+    }
+
+    // Parse a `.shp` buffer together with its paired `.dbf` attribute table
+    // into a GeoJSON FeatureCollection whose features carry DBF properties.
+    #[wasm_bindgen]
+    pub fn parse_with_attributes(&self, shp: &[u8], dbf: &[u8]) -> Result<JsValue, JsError> {
+        parser::parse_with_attributes(shp, dbf)
+    }
 }
 
 // Tests module
@@ -96,7 +223,31 @@ mod tests {
     fn test_process_point() {
         let processor = ShapefileProcessor::new();
         let coords = vec![1.0, 2.0];
-        let result = processor.process_geometry(1, &coords);
+        let result = processor.process_geometry(1, &coords, &[], &[], &[]);
+        assert!(result.is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_process_point_z() {
+        let processor = ShapefileProcessor::new();
+        let coords = vec![1.0, 2.0];
+        let result = processor.process_geometry(11, &coords, &[10.0], &[], &[]);
+        assert!(result.is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_process_polyline_m() {
+        let processor = ShapefileProcessor::new();
+        let coords = vec![0.0, 0.0, 1.0, 1.0];
+        let result = processor.process_geometry(23, &coords, &[], &[5.0, 6.0], &[]);
+        assert!(result.is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_process_multipatch() {
+        let processor = ShapefileProcessor::new();
+        let square = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let result = processor.process_geometry(31, &square, &[1.0, 1.0, 1.0, 1.0], &[], &[2]); // OuterRing
         assert!(result.is_ok());
     }
 }
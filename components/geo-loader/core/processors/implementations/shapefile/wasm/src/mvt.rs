@@ -0,0 +1,484 @@
+use js_sys::{Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+use crate::geometry::is_clockwise;
+use crate::processor::{self, GeomProcessor};
+
+// Default tile extent in integer tile units, per the Mapbox Vector Tile spec.
+pub const DEFAULT_EXTENT: u32 = 4096;
+
+// Command integers per the MVT spec (google/protobuf varint encoded).
+const CMD_MOVE_TO: u32 = 1;
+const CMD_LINE_TO: u32 = 2;
+const CMD_CLOSE_PATH: u32 = 7;
+
+// Geometry type enum values from the vector_tile.proto Tile.GeomType.
+const GEOM_POINT: u32 = 1;
+const GEOM_LINESTRING: u32 = 2;
+const GEOM_POLYGON: u32 = 3;
+
+fn command(id: u32, count: u32) -> u32 {
+    (count << 3) | (id & 0x7)
+}
+
+fn zigzag(n: i32) -> u32 {
+    ((n << 1) ^ (n >> 31)) as u32
+}
+
+fn zigzag64(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)) as u64
+}
+
+// Minimal protobuf writer covering the varint/length-delimited wire types
+// a vector tile layer needs; not a general-purpose protobuf implementation.
+struct ProtoWriter {
+    buf: Vec<u8>,
+}
+
+impl ProtoWriter {
+    fn new() -> Self {
+        ProtoWriter { buf: Vec::new() }
+    }
+
+    fn write_varint(&mut self, mut value: u64) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                self.buf.push(byte);
+                break;
+            }
+            self.buf.push(byte | 0x80);
+        }
+    }
+
+    fn write_tag(&mut self, field_number: u32, wire_type: u32) {
+        self.write_varint(((field_number << 3) | wire_type) as u64);
+    }
+
+    fn write_varint_field(&mut self, field_number: u32, value: u64) {
+        self.write_tag(field_number, 0);
+        self.write_varint(value);
+    }
+
+    fn write_bytes_field(&mut self, field_number: u32, bytes: &[u8]) {
+        self.write_tag(field_number, 2);
+        self.write_varint(bytes.len() as u64);
+        self.buf.extend_from_slice(bytes);
+    }
+
+    fn write_string_field(&mut self, field_number: u32, value: &str) {
+        self.write_bytes_field(field_number, value.as_bytes());
+    }
+
+    fn write_double_field(&mut self, field_number: u32, value: f64) {
+        self.write_tag(field_number, 1); // wire type 1: 64-bit
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+// Quantizes world-space coordinates (normalized 0..1 web-mercator space) into
+// integer tile-local coordinates in `0..=extent` for a given z/x/y tile.
+#[derive(Clone, Copy)]
+struct TileContext {
+    tile_x: u32,
+    tile_y: u32,
+    tile_z: u32,
+    extent: u32,
+}
+
+impl TileContext {
+    // Clamps to `0..=extent` so a geometry that straddles (or sits entirely
+    // outside) this tile's boundary still produces valid, in-range MVT
+    // coordinates instead of command-stream values a renderer would have to
+    // special-case or reject.
+    fn quantize(&self, x: f64, y: f64) -> (i32, i32) {
+        let scale = (1u64 << self.tile_z) as f64;
+        let px = ((x * scale - self.tile_x as f64) * self.extent as f64).round() as i32;
+        let py = ((y * scale - self.tile_y as f64) * self.extent as f64).round() as i32;
+        (px.clamp(0, self.extent as i32), py.clamp(0, self.extent as i32))
+    }
+}
+
+fn ensure_winding(points: &mut [(f64, f64)], want_clockwise: bool) -> Result<(), JsError> {
+    let flat: Vec<f64> = points.iter().flat_map(|&(x, y)| vec![x, y]).collect();
+    if is_clockwise(&flat)? != want_clockwise {
+        points.reverse();
+    }
+    Ok(())
+}
+
+// A `GeomProcessor` that quantizes each visited point into tile space and
+// emits MoveTo/LineTo/ClosePath commands as parts complete, so the command
+// stream never needs the whole geometry materialized up front.
+struct MvtWriter {
+    ctx: TileContext,
+    cursor: (i32, i32),
+    current_part: Vec<(i32, i32)>,
+    commands: Vec<u32>,
+}
+
+impl MvtWriter {
+    fn new(ctx: TileContext) -> Self {
+        MvtWriter {
+            ctx,
+            cursor: (0, 0),
+            current_part: Vec::new(),
+            commands: Vec::new(),
+        }
+    }
+
+    fn into_commands(self) -> Vec<u32> {
+        self.commands
+    }
+
+    fn emit_part(&mut self, closed: bool) {
+        if self.current_part.is_empty() {
+            return;
+        }
+        let points = std::mem::take(&mut self.current_part);
+
+        let (mx, my) = points[0];
+        self.commands.push(command(CMD_MOVE_TO, 1));
+        self.commands.push(zigzag(mx - self.cursor.0));
+        self.commands.push(zigzag(my - self.cursor.1));
+        self.cursor = (mx, my);
+
+        let mut line_params = Vec::new();
+        for &(px, py) in &points[1..] {
+            let (dx, dy) = (px - self.cursor.0, py - self.cursor.1);
+            if dx == 0 && dy == 0 {
+                continue; // skip points that don't move the cursor
+            }
+            line_params.push(zigzag(dx));
+            line_params.push(zigzag(dy));
+            self.cursor = (px, py);
+        }
+
+        if !line_params.is_empty() {
+            self.commands.push(command(CMD_LINE_TO, (line_params.len() / 2) as u32));
+            self.commands.extend(line_params);
+        }
+
+        if closed {
+            self.commands.push(command(CMD_CLOSE_PATH, 1));
+        }
+    }
+
+    fn emit_multi_point(&mut self) {
+        if self.current_part.is_empty() {
+            return;
+        }
+        let points = std::mem::take(&mut self.current_part);
+
+        let mut params = Vec::new();
+        let mut count = 0u32;
+        for &(px, py) in &points {
+            let (dx, dy) = (px - self.cursor.0, py - self.cursor.1);
+            if dx == 0 && dy == 0 && count > 0 {
+                continue;
+            }
+            params.push(zigzag(dx));
+            params.push(zigzag(dy));
+            self.cursor = (px, py);
+            count += 1;
+        }
+        self.commands.push(command(CMD_MOVE_TO, count));
+        self.commands.extend(params);
+    }
+}
+
+impl GeomProcessor for MvtWriter {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<(), JsError> {
+        self.current_part.push(self.ctx.quantize(x, y));
+        Ok(())
+    }
+
+    fn point_end(&mut self, _idx: usize) -> Result<(), JsError> {
+        self.emit_part(false);
+        Ok(())
+    }
+
+    fn multi_point_end(&mut self, _idx: usize) -> Result<(), JsError> {
+        self.emit_multi_point();
+        Ok(())
+    }
+
+    fn linestring_end(&mut self, is_ring: bool, _idx: usize) -> Result<(), JsError> {
+        self.emit_part(is_ring);
+        Ok(())
+    }
+}
+
+fn build_geometry(
+    shape_type: u32,
+    coordinates: &[f64],
+    ring_sizes: &[usize],
+    ctx: &TileContext,
+) -> Result<(u32, Vec<u32>), JsError> {
+    if !coordinates.len().is_multiple_of(2) {
+        return Err(JsError::new("Coordinates array must have even length"));
+    }
+
+    let mut writer = MvtWriter::new(*ctx);
+
+    let geom_type = match shape_type {
+        1 => {
+            // Point
+            if coordinates.len() != 2 {
+                return Err(JsError::new("Point must have exactly 2 coordinates"));
+            }
+            processor::walk_point(coordinates[0], coordinates[1], &mut writer)?;
+            GEOM_POINT
+        }
+        8 => {
+            // MultiPoint
+            processor::walk_multi_point(coordinates, &mut writer)?;
+            GEOM_POINT
+        }
+        3 => {
+            // PolyLine
+            processor::walk_linestring(coordinates, false, &mut writer)?;
+            GEOM_LINESTRING
+        }
+        5 => {
+            // Polygon, exterior ring first and holes (if any) after, per
+            // `ring_sizes` (empty means treat the whole buffer as one ring,
+            // the same simplification `ShapefileProcessor::process_geometry`
+            // uses). Per the MVT spec the exterior ring must be wound
+            // clockwise and each hole counter-clockwise.
+            let rings = processor::ring_slices(coordinates, ring_sizes)?;
+            let mut flat = Vec::with_capacity(coordinates.len());
+            let mut wound_sizes = Vec::with_capacity(rings.len());
+            for (i, ring) in rings.iter().enumerate() {
+                let mut points: Vec<(f64, f64)> = ring.chunks(2).map(|c| (c[0], c[1])).collect();
+                if points.len() >= 3 {
+                    ensure_winding(&mut points, i == 0)?;
+                }
+                wound_sizes.push(points.len());
+                flat.extend(points.into_iter().flat_map(|(x, y)| [x, y]));
+            }
+            processor::walk_polygon(&flat, &wound_sizes, false, 0, &mut writer)?;
+            GEOM_POLYGON
+        }
+        _ => return Err(JsError::new("Unsupported shape type for vector tile encoding")),
+    };
+
+    Ok((geom_type, writer.into_commands()))
+}
+
+// Encodes one JS property value as an MVT `Value` message (string/double/
+// sint/bool — the subset JSON property values actually map onto).
+fn encode_value(value: &JsValue) -> Result<Vec<u8>, JsError> {
+    let mut writer = ProtoWriter::new();
+
+    if let Some(s) = value.as_string() {
+        writer.write_string_field(1, &s); // string_value
+    } else if let Some(b) = value.as_bool() {
+        writer.write_varint_field(7, b as u64); // bool_value
+    } else if let Some(n) = value.as_f64() {
+        if n.is_finite() && n.fract() == 0.0 && (i64::MIN as f64..=i64::MAX as f64).contains(&n) {
+            writer.write_tag(6, 0); // sint_value
+            writer.write_varint(zigzag64(n as i64));
+        } else {
+            writer.write_double_field(3, n); // double_value
+        }
+    } else {
+        return Err(JsError::new("Unsupported property value type for vector tile tags"));
+    }
+
+    Ok(writer.into_bytes())
+}
+
+// Reads a JS properties object into the MVT layer's keys/values tables plus
+// this feature's tags (flat key-index, value-index pairs). Since a tile only
+// ever holds the one feature `encode_tile` is given, each property gets its
+// own keys/values slot (index `i` in both) rather than deduplicating shared
+// keys/values across features the way a multi-feature layer would.
+fn build_tags(properties: &JsValue) -> Result<(Vec<String>, Vec<Vec<u8>>, Vec<u32>), JsError> {
+    if properties.is_undefined() || properties.is_null() {
+        return Ok((Vec::new(), Vec::new(), Vec::new()));
+    }
+
+    let obj = Object::from(properties.clone());
+    let keys_js = Object::keys(&obj);
+
+    let mut keys = Vec::with_capacity(keys_js.length() as usize);
+    let mut values = Vec::with_capacity(keys_js.length() as usize);
+    let mut tags = Vec::with_capacity(keys_js.length() as usize * 2);
+
+    for (i, key) in keys_js.iter().enumerate() {
+        let key_str = key.as_string().ok_or_else(|| JsError::new("Property key must be a string"))?;
+        let value = Reflect::get(&obj, &key).map_err(|_| JsError::new("Failed to read property value"))?;
+
+        keys.push(key_str);
+        values.push(encode_value(&value)?);
+        tags.push(i as u32);
+        tags.push(i as u32);
+    }
+
+    Ok((keys, values, tags))
+}
+
+fn encode_feature(geom_type: u32, commands: Vec<u32>, tags: Vec<u32>) -> Vec<u8> {
+    let mut feature = ProtoWriter::new();
+    if !tags.is_empty() {
+        let mut tags_writer = ProtoWriter::new();
+        for tag in &tags {
+            tags_writer.write_varint(*tag as u64);
+        }
+        feature.write_bytes_field(2, &tags_writer.into_bytes()); // Feature.tags (packed)
+    }
+    feature.write_varint_field(3, geom_type as u64); // Feature.type
+    let mut geometry = ProtoWriter::new();
+    for &value in &commands {
+        geometry.write_varint(value as u64);
+    }
+    feature.write_bytes_field(4, &geometry.into_bytes()); // Feature.geometry (packed)
+    feature.into_bytes()
+}
+
+fn encode_layer(
+    geom_type: u32,
+    extent: u32,
+    commands: Vec<u32>,
+    keys: Vec<String>,
+    values: Vec<Vec<u8>>,
+    tags: Vec<u32>,
+) -> Vec<u8> {
+    let feature_bytes = encode_feature(geom_type, commands, tags);
+
+    let mut layer = ProtoWriter::new();
+    layer.write_varint_field(15, 2); // Layer.version
+    layer.write_string_field(1, "shapefile"); // Layer.name
+    layer.write_bytes_field(2, &feature_bytes); // Layer.features
+    for key in &keys {
+        layer.write_string_field(3, key); // Layer.keys
+    }
+    for value in &values {
+        layer.write_bytes_field(4, value); // Layer.values
+    }
+    layer.write_varint_field(5, extent as u64); // Layer.extent
+    layer.into_bytes()
+}
+
+// Encodes a single geometry, with its properties carried as integer-keyed
+// tags, into a protobuf-encoded Mapbox Vector Tile byte buffer containing one
+// layer named "shapefile" with one feature.
+pub fn encode_tile(
+    shape_type: u32,
+    coordinates: &[f64],
+    ring_sizes: &[usize],
+    tile_x: u32,
+    tile_y: u32,
+    tile_z: u32,
+    extent: u32,
+    properties: &JsValue,
+) -> Result<Vec<u8>, JsError> {
+    let ctx = TileContext {
+        tile_x,
+        tile_y,
+        tile_z,
+        extent,
+    };
+    let (geom_type, commands) = build_geometry(shape_type, coordinates, ring_sizes, &ctx)?;
+    let (keys, values, tags) = build_tags(properties)?;
+    let layer_bytes = encode_layer(geom_type, extent, commands, keys, values, tags);
+
+    let mut tile = ProtoWriter::new();
+    tile.write_bytes_field(3, &layer_bytes); // Tile.layers
+    Ok(tile.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_zigzag_roundtrip() {
+        assert_eq!(zigzag(0), 0);
+        assert_eq!(zigzag(-1), 1);
+        assert_eq!(zigzag(1), 2);
+        assert_eq!(zigzag(-2), 3);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encode_tile_point_not_empty() {
+        let tile = encode_tile(1, &[0.5, 0.5], &[], 0, 0, 1, DEFAULT_EXTENT, &JsValue::UNDEFINED).unwrap();
+        assert!(!tile.is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encode_tile_unsupported_shape_type() {
+        assert!(encode_tile(31, &[0.0, 0.0], &[], 0, 0, 0, DEFAULT_EXTENT, &JsValue::UNDEFINED).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encode_tile_rejects_odd_coordinates() {
+        assert!(encode_tile(3, &[0.0, 0.0, 1.0], &[], 0, 0, 0, DEFAULT_EXTENT, &JsValue::UNDEFINED).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_quantize_clamps_out_of_range_coordinates() {
+        let ctx = TileContext { tile_x: 0, tile_y: 0, tile_z: 0, extent: DEFAULT_EXTENT };
+        // World-space (2.0, -1.0) is far outside tile (0, 0) at z0.
+        let (px, py) = ctx.quantize(2.0, -1.0);
+        assert_eq!(px, DEFAULT_EXTENT as i32);
+        assert_eq!(py, 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encode_tile_point_outside_tile_is_clamped_not_rejected() {
+        // A point well outside tile (0,0,0) should still encode, not error.
+        let tile = encode_tile(1, &[5.0, 5.0], &[], 0, 0, 0, DEFAULT_EXTENT, &JsValue::UNDEFINED).unwrap();
+        assert!(!tile.is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_build_geometry_polygon_with_hole_emits_two_rings() {
+        let ctx = TileContext { tile_x: 0, tile_y: 0, tile_z: 0, extent: DEFAULT_EXTENT };
+        let outer = [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+        let hole = [0.25, 0.25, 0.75, 0.25, 0.75, 0.75, 0.25, 0.75];
+        let coords: Vec<f64> = outer.iter().chain(hole.iter()).copied().collect();
+
+        let (geom_type, commands) = build_geometry(5, &coords, &[4, 4], &ctx).unwrap();
+        assert_eq!(geom_type, GEOM_POLYGON);
+        // Each ring starts with its own MoveTo command; two rings in means
+        // two MoveTo commands out, not one ring silently dropped.
+        let move_to_count = commands.iter().filter(|&&c| c & 0x7 == CMD_MOVE_TO).count();
+        assert_eq!(move_to_count, 2);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_build_tags_interns_properties_by_position() {
+        let obj = Object::new();
+        Reflect::set(&obj, &JsValue::from_str("name"), &JsValue::from_str("Main St")).unwrap();
+        Reflect::set(&obj, &JsValue::from_str("lanes"), &JsValue::from_f64(2.0)).unwrap();
+
+        let (keys, values, tags) = build_tags(&obj.into()).unwrap();
+        assert_eq!(keys, vec!["name".to_string(), "lanes".to_string()]);
+        assert_eq!(values.len(), 2);
+        assert_eq!(tags, vec![0, 0, 1, 1]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_build_tags_empty_for_undefined_properties() {
+        let (keys, values, tags) = build_tags(&JsValue::UNDEFINED).unwrap();
+        assert!(keys.is_empty());
+        assert!(values.is_empty());
+        assert!(tags.is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encode_tile_with_properties_not_empty() {
+        let obj = Object::new();
+        Reflect::set(&obj, &JsValue::from_str("name"), &JsValue::from_str("Main St")).unwrap();
+        let tile = encode_tile(1, &[0.5, 0.5], &[], 0, 0, 1, DEFAULT_EXTENT, &obj.into()).unwrap();
+        assert!(!tile.is_empty());
+    }
+}
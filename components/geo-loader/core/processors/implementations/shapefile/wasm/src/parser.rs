@@ -0,0 +1,601 @@
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+use crate::dbf;
+use crate::geometry;
+use crate::validation;
+
+const HEADER_LENGTH: usize = 100;
+
+// Cursor-based byte reader matching the shapefile spec's mixed endianness:
+// big-endian for the main header and record headers, little-endian for
+// everything else (version, shape type, bounding boxes, coordinates).
+struct ByteReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        ByteReader { buf, pos: 0 }
+    }
+
+    fn seek(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+
+    fn require(&self, n: usize) -> Result<(), JsError> {
+        if self.pos + n > self.buf.len() {
+            return Err(JsError::new("Unexpected end of shapefile buffer"));
+        }
+        Ok(())
+    }
+
+    fn skip(&mut self, n: usize) -> Result<(), JsError> {
+        self.require(n)?;
+        self.pos += n;
+        Ok(())
+    }
+
+    fn i32_be(&mut self) -> Result<i32, JsError> {
+        self.require(4)?;
+        let bytes = self.buf[self.pos..self.pos + 4].try_into().unwrap();
+        self.pos += 4;
+        Ok(i32::from_be_bytes(bytes))
+    }
+
+    fn i32_le(&mut self) -> Result<i32, JsError> {
+        self.require(4)?;
+        let bytes = self.buf[self.pos..self.pos + 4].try_into().unwrap();
+        self.pos += 4;
+        Ok(i32::from_le_bytes(bytes))
+    }
+
+    fn f64_le(&mut self) -> Result<f64, JsError> {
+        self.require(8)?;
+        let bytes = self.buf[self.pos..self.pos + 8].try_into().unwrap();
+        self.pos += 8;
+        Ok(f64::from_le_bytes(bytes))
+    }
+}
+
+fn set(obj: &Object, key: &str, value: &JsValue) -> Result<(), JsError> {
+    Reflect::set(obj, &JsValue::from_str(key), value)
+        .map(|_| ())
+        .map_err(|_| JsError::new(&format!("Failed to set '{}' on JS feature object", key)))
+}
+
+// Wraps a `convert_*`-produced geometry value into a GeoJSON Feature. Built as
+// a plain JS object (rather than a serde struct) since the geometry can be
+// whichever shape the matching `convert_*` call already serialized.
+pub(crate) fn make_feature(geometry: JsValue, properties: JsValue) -> Result<JsValue, JsError> {
+    let feature = Object::new();
+    set(&feature, "type", &JsValue::from_str("Feature"))?;
+    set(&feature, "geometry", &geometry)?;
+    set(&feature, "properties", &properties)?;
+    Ok(feature.into())
+}
+
+fn make_feature_collection(features: Vec<JsValue>) -> Result<JsValue, JsError> {
+    let array = Array::new();
+    for feature in features {
+        array.push(&feature);
+    }
+    let collection = Object::new();
+    set(&collection, "type", &JsValue::from_str("FeatureCollection"))?;
+    set(&collection, "features", &array.into())?;
+    Ok(collection.into())
+}
+
+// Reads the bounding box and part/point counts shared by PolyLine/Polygon
+// (and their Z/M variants), returning flat coordinates, per-ring point
+// counts, and the point count so the Z/M arrays (if any) can be sized.
+fn read_parts_and_points(reader: &mut ByteReader) -> Result<(Vec<f64>, Vec<usize>, usize), JsError> {
+    reader.skip(32)?; // bounding box: xmin, ymin, xmax, ymax
+
+    let num_parts = reader.i32_le()?;
+    let num_points = reader.i32_le()?;
+    validation::validate_parts_and_points(num_parts, num_points, "shapefile record")?;
+
+    let mut part_starts = Vec::with_capacity(num_parts as usize);
+    for _ in 0..num_parts {
+        part_starts.push(reader.i32_le()?);
+    }
+
+    let mut ring_sizes = Vec::with_capacity(part_starts.len());
+    for (i, &start) in part_starts.iter().enumerate() {
+        let end = part_starts.get(i + 1).copied().unwrap_or(num_points);
+        validation::validate_part_index(start, num_points)?;
+        validation::validate_part_range(start, end, i as i32)?;
+        ring_sizes.push((end - start) as usize);
+    }
+
+    let mut coordinates = Vec::with_capacity(num_points as usize * 2);
+    for _ in 0..num_points {
+        coordinates.push(reader.f64_le()?);
+        coordinates.push(reader.f64_le()?);
+    }
+
+    Ok((coordinates, ring_sizes, num_points as usize))
+}
+
+// Reads the bounding box, part/point counts, and points for a MultiPatch
+// record: the same layout as `read_parts_and_points`, but with an extra
+// PartType array (parallel to Parts) identifying each part as a ring,
+// triangle strip, or triangle fan.
+fn read_multipatch_parts(reader: &mut ByteReader) -> Result<(Vec<f64>, Vec<usize>, Vec<u32>, usize), JsError> {
+    reader.skip(32)?; // bounding box: xmin, ymin, xmax, ymax
+
+    let num_parts = reader.i32_le()?;
+    let num_points = reader.i32_le()?;
+    validation::validate_parts_and_points(num_parts, num_points, "shapefile record")?;
+
+    let mut part_starts = Vec::with_capacity(num_parts as usize);
+    for _ in 0..num_parts {
+        part_starts.push(reader.i32_le()?);
+    }
+
+    let mut part_types = Vec::with_capacity(num_parts as usize);
+    for _ in 0..num_parts {
+        part_types.push(reader.i32_le()? as u32);
+    }
+
+    let mut ring_sizes = Vec::with_capacity(part_starts.len());
+    for (i, &start) in part_starts.iter().enumerate() {
+        let end = part_starts.get(i + 1).copied().unwrap_or(num_points);
+        validation::validate_part_index(start, num_points)?;
+        validation::validate_part_range(start, end, i as i32)?;
+        ring_sizes.push((end - start) as usize);
+    }
+
+    let mut coordinates = Vec::with_capacity(num_points as usize * 2);
+    for _ in 0..num_points {
+        coordinates.push(reader.f64_le()?);
+        coordinates.push(reader.f64_le()?);
+    }
+
+    Ok((coordinates, ring_sizes, part_types, num_points as usize))
+}
+
+fn read_multipoint(reader: &mut ByteReader) -> Result<(Vec<f64>, usize), JsError> {
+    reader.skip(32)?; // bounding box
+    let num_points = reader.i32_le()?;
+
+    let mut coordinates = Vec::with_capacity(num_points as usize * 2);
+    for _ in 0..num_points {
+        coordinates.push(reader.f64_le()?);
+        coordinates.push(reader.f64_le()?);
+    }
+
+    Ok((coordinates, num_points as usize))
+}
+
+// The optional Z and M ordinate arrays `read_optional_zm` reads, each absent
+// if the shape type/record don't carry it.
+type OptionalZm = (Option<Vec<f64>>, Option<Vec<f64>>);
+
+// Reads the optional Z and/or M ordinate arrays that trail a part/point-list
+// shape, each as `[min, max, value * num_points]`. Z is only read for the
+// Z-flavored shape types. For both arrays, presence is detected from how many
+// bytes remain before `record_end`; a record too short to hold one yields `None`.
+fn read_optional_zm(
+    reader: &mut ByteReader,
+    shape_type: u32,
+    num_points: usize,
+    record_end: usize,
+) -> Result<OptionalZm, JsError> {
+    let array_bytes = 16 + num_points * 8; // min/max doubles plus one value per point
+    let is_z_shape = matches!(shape_type, 13 | 15 | 18 | 31);
+
+    let z = if is_z_shape && reader.pos + array_bytes <= record_end {
+        reader.f64_le()?; // Zmin
+        reader.f64_le()?; // Zmax
+        Some((0..num_points).map(|_| reader.f64_le()).collect::<Result<Vec<f64>, JsError>>()?)
+    } else {
+        None
+    };
+
+    let m = if reader.pos + array_bytes <= record_end {
+        reader.f64_le()?; // Mmin
+        reader.f64_le()?; // Mmax
+        Some((0..num_points).map(|_| reader.f64_le()).collect::<Result<Vec<f64>, JsError>>()?)
+    } else {
+        None
+    };
+
+    Ok((z, m))
+}
+
+// Reads one record's geometry and converts it via the matching `convert_*`
+// function, returning `None` for shape types with no GeoJSON equivalent here
+// (just Null).
+fn parse_record_geometry(
+    reader: &mut ByteReader,
+    shape_type: u32,
+    record_end: usize,
+) -> Result<Option<JsValue>, JsError> {
+    if shape_type == 0 || !validation::validate_shape_type(shape_type)? {
+        return Ok(None);
+    }
+
+    match shape_type {
+        1 => {
+            let x = reader.f64_le()?;
+            let y = reader.f64_le()?;
+            Ok(Some(geometry::convert_point(x, y)?))
+        }
+        11 | 21 => {
+            let x = reader.f64_le()?;
+            let y = reader.f64_le()?;
+            let z = if shape_type == 11 && reader.pos + 8 <= record_end {
+                Some(reader.f64_le()?)
+            } else {
+                None
+            };
+            let m = if reader.pos + 8 <= record_end {
+                Some(reader.f64_le()?)
+            } else {
+                None
+            };
+            Ok(Some(geometry::convert_point_z(x, y, z, m)?))
+        }
+        8 | 18 | 28 => {
+            let (coordinates, num_points) = read_multipoint(reader)?;
+            if shape_type == 8 {
+                Ok(Some(geometry::convert_multi_point(&coordinates)?))
+            } else {
+                let (z, m) = read_optional_zm(reader, shape_type, num_points, record_end)?;
+                Ok(Some(geometry::convert_multi_point_z(&coordinates, z.as_deref(), m.as_deref())?))
+            }
+        }
+        3 | 13 | 23 => {
+            let (coordinates, ring_sizes, num_points) = read_parts_and_points(reader)?;
+            if shape_type == 3 {
+                Ok(Some(geometry::convert_polyline(&coordinates, &ring_sizes)?))
+            } else {
+                let (z, m) = read_optional_zm(reader, shape_type, num_points, record_end)?;
+                Ok(Some(geometry::convert_polyline_z(&coordinates, &ring_sizes, z.as_deref(), m.as_deref())?))
+            }
+        }
+        5 | 15 | 25 => {
+            let (coordinates, ring_sizes, num_points) = read_parts_and_points(reader)?;
+            if shape_type == 5 {
+                Ok(Some(geometry::convert_polygon(&coordinates, &ring_sizes)?))
+            } else {
+                let (z, m) = read_optional_zm(reader, shape_type, num_points, record_end)?;
+                Ok(Some(geometry::convert_polygon_z(&coordinates, &ring_sizes, z.as_deref(), m.as_deref())?))
+            }
+        }
+        31 => {
+            let (coordinates, ring_sizes, part_types, num_points) = read_multipatch_parts(reader)?;
+            let (z, m) = read_optional_zm(reader, shape_type, num_points, record_end)?;
+            Ok(Some(geometry::convert_multipatch(&coordinates, &ring_sizes, &part_types, z.as_deref(), m.as_deref())?))
+        }
+        _ => Ok(None),
+    }
+}
+
+// Reads a `.shp` buffer end to end and converts every record's geometry via
+// the matching `convert_*` function. Each record's declared content length
+// (not our own field-by-field bookkeeping) decides where the next record
+// starts, so a geometry that does not use all of its declared bytes does not
+// misalign the next record. A record that fails validation or runs past the
+// buffer fails the whole parse. Returns one slot per record in file order — `None` for
+// shape types with no GeoJSON equivalent (just Null) — so a caller zipping
+// against another per-record source (e.g. DBF rows) stays aligned even when
+// some records are skipped.
+fn parse_geometries(buffer: &[u8]) -> Result<Vec<Option<JsValue>>, JsError> {
+    validation::validate_header_buffer(buffer.len())?;
+
+    let mut reader = ByteReader::new(buffer);
+    let file_code = reader.i32_be()?;
+    validation::validate_file_code(file_code)?;
+
+    reader.seek(24);
+    let file_length_bytes = (reader.i32_be()? as usize) * 2; // stored as 16-bit words
+    validation::validate_file_length(file_length_bytes, buffer.len())?;
+
+    let version = reader.i32_le()?;
+    validation::validate_version(version)?;
+
+    reader.seek(36);
+    let x_min = reader.f64_le()?;
+    let y_min = reader.f64_le()?;
+    let x_max = reader.f64_le()?;
+    let y_max = reader.f64_le()?;
+    validation::validate_bounding_box(x_min, y_min, x_max, y_max)?;
+
+    let mut geometries = Vec::new();
+    let mut offset = HEADER_LENGTH;
+
+    while offset + 8 <= file_length_bytes {
+        reader.seek(offset);
+        let record_number = reader.i32_be()?;
+        let content_length_words = reader.i32_be()?;
+        validation::validate_record_content_length(content_length_words, record_number)?;
+
+        let content_length_bytes = (content_length_words as usize) * 2;
+        let content_start = offset + 8;
+        validation::validate_record_buffer_space(content_start, content_length_bytes, buffer.len(), record_number)?;
+        let record_end = content_start + content_length_bytes;
+
+        reader.seek(content_start);
+        let shape_type = reader.i32_le()? as u32;
+
+        geometries.push(parse_record_geometry(&mut reader, shape_type, record_end)?);
+
+        offset = record_end;
+    }
+
+    Ok(geometries)
+}
+
+// Parses a `.shp` buffer into a GeoJSON FeatureCollection with empty
+// properties on every feature.
+pub fn parse(buffer: &[u8]) -> Result<JsValue, JsError> {
+    let features = parse_geometries(buffer)?
+        .into_iter()
+        .flatten()
+        .map(|geometry| make_feature(geometry, Object::new().into()))
+        .collect::<Result<Vec<JsValue>, JsError>>()?;
+    make_feature_collection(features)
+}
+
+// Parses a `.shp` buffer together with its paired `.dbf` attribute table,
+// zipping each geometry with the DBF record at the same index into a
+// FeatureCollection — mirroring how GDAL's vector layer pairs geometry and
+// fields. Zipping happens by *record* index on both sides (`parse_dbf` keeps
+// one slot per on-disk DBF record, `null` for deleted ones, rather than
+// compacting them away), so a skipped Null shp record or a deleted DBF
+// record in the middle of either file doesn't shift every later feature's
+// attributes out of alignment — a deleted DBF record just pairs its shp
+// geometry with empty properties. If the two files disagree on record
+// count, shp records past the end of the DBF still become features with
+// empty properties, and extra DBF records are ignored.
+pub fn parse_with_attributes(shp: &[u8], dbf_buffer: &[u8]) -> Result<JsValue, JsError> {
+    let geometries = parse_geometries(shp)?;
+    let attributes: Array = dbf::parse_dbf(dbf_buffer)?.into();
+
+    let features = geometries
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, geometry)| geometry.map(|g| (i, g)))
+        .map(|(i, geometry)| {
+            let properties = if (i as u32) < attributes.length() {
+                attributes.get(i as u32)
+            } else {
+                JsValue::NULL
+            };
+            // A deleted DBF record (`null`) or one past the end of the
+            // table still gets a feature, just with empty properties.
+            let properties = if properties.is_null() { Object::new().into() } else { properties };
+            make_feature(geometry, properties)
+        })
+        .collect::<Result<Vec<JsValue>, JsError>>()?;
+
+    make_feature_collection(features)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    // The DBF header is 32 bytes, unlike the 100-byte `.shp` `HEADER_LENGTH`.
+    const DBF_HEADER_LENGTH: usize = 32;
+
+    fn push_header(buf: &mut Vec<u8>, file_length_words: i32, shape_type: i32) {
+        buf.extend_from_slice(&9994i32.to_be_bytes());
+        buf.extend_from_slice(&[0u8; 20]); // 5 unused i32s
+        buf.extend_from_slice(&file_length_words.to_be_bytes());
+        buf.extend_from_slice(&1000i32.to_le_bytes());
+        buf.extend_from_slice(&shape_type.to_le_bytes());
+        for value in [0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 0.0, 0.0] {
+            buf.extend_from_slice(&f64::to_le_bytes(value));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_single_point_record() {
+        let mut buf = Vec::new();
+        // Header (100) + record header (8) + shape type (4) + x,y (16) = 128 bytes = 64 words.
+        push_header(&mut buf, 64, 1);
+        buf.extend_from_slice(&1i32.to_be_bytes()); // record number
+        buf.extend_from_slice(&10i32.to_be_bytes()); // content length in words: (4+16)/2
+        buf.extend_from_slice(&1i32.to_le_bytes()); // shape type: Point
+        buf.extend_from_slice(&5.0f64.to_le_bytes());
+        buf.extend_from_slice(&5.0f64.to_le_bytes());
+
+        let result = parse(&buf);
+        assert!(result.is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_rejects_short_buffer() {
+        assert!(parse(&[0u8; 10]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_rejects_bad_file_code() {
+        let mut buf = vec![0u8; HEADER_LENGTH];
+        buf[0..4].copy_from_slice(&0i32.to_be_bytes());
+        assert!(parse(&buf).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_with_attributes_zips_properties() {
+        let mut shp = Vec::new();
+        push_header(&mut shp, 64, 1);
+        shp.extend_from_slice(&1i32.to_be_bytes());
+        shp.extend_from_slice(&10i32.to_be_bytes());
+        shp.extend_from_slice(&1i32.to_le_bytes());
+        shp.extend_from_slice(&5.0f64.to_le_bytes());
+        shp.extend_from_slice(&5.0f64.to_le_bytes());
+
+        // A field-less DBF with a single (non-deleted) record.
+        let mut dbf_buf = vec![0u8; DBF_HEADER_LENGTH];
+        dbf_buf[4..8].copy_from_slice(&1u32.to_le_bytes());
+        dbf_buf[8..10].copy_from_slice(&33u16.to_le_bytes());
+        dbf_buf[10..12].copy_from_slice(&1u16.to_le_bytes());
+        dbf_buf.push(0x0D); // field descriptor terminator, no fields
+        dbf_buf.push(b' '); // one record, not deleted
+
+        let result = parse_with_attributes(&shp, &dbf_buf);
+        assert!(result.is_ok());
+
+        let collection = Object::from(result.unwrap());
+        let features: Array = Reflect::get(&collection, &JsValue::from_str("features")).unwrap().into();
+        assert_eq!(features.length(), 1);
+    }
+
+    fn push_field_less_dbf(row_count: u32) -> Vec<u8> {
+        let mut buf = vec![0u8; DBF_HEADER_LENGTH];
+        buf[4..8].copy_from_slice(&row_count.to_le_bytes());
+        buf[8..10].copy_from_slice(&33u16.to_le_bytes());
+        buf[10..12].copy_from_slice(&1u16.to_le_bytes());
+        buf.push(0x0D);
+        buf.resize(buf.len() + row_count as usize, b' '); // not deleted
+        buf
+    }
+
+    // A skipped Null-shape record in the middle of the file must not shift
+    // the DBF zip for records after it.
+    #[wasm_bindgen_test]
+    fn test_parse_with_attributes_skips_null_record_without_shifting_zip() {
+        let mut shp = Vec::new();
+        // Total: header(100) + point(8+20) + null(8+4) + point(8+20) = 168 bytes = 84 words.
+        push_header(&mut shp, 84, 1);
+
+        shp.extend_from_slice(&1i32.to_be_bytes()); // record 1: Point
+        shp.extend_from_slice(&10i32.to_be_bytes());
+        shp.extend_from_slice(&1i32.to_le_bytes());
+        shp.extend_from_slice(&1.0f64.to_le_bytes());
+        shp.extend_from_slice(&1.0f64.to_le_bytes());
+
+        shp.extend_from_slice(&2i32.to_be_bytes()); // record 2: Null
+        shp.extend_from_slice(&2i32.to_be_bytes());
+        shp.extend_from_slice(&0i32.to_le_bytes());
+
+        shp.extend_from_slice(&3i32.to_be_bytes()); // record 3: Point
+        shp.extend_from_slice(&10i32.to_be_bytes());
+        shp.extend_from_slice(&1i32.to_le_bytes());
+        shp.extend_from_slice(&2.0f64.to_le_bytes());
+        shp.extend_from_slice(&2.0f64.to_le_bytes());
+
+        let dbf_buf = push_single_char_field_dbf(&[(false, 'A'), (false, 'B'), (false, 'C')]);
+
+        let result = parse_with_attributes(&shp, &dbf_buf).unwrap();
+        let collection = Object::from(result);
+        let features: Array = Reflect::get(&collection, &JsValue::from_str("features")).unwrap().into();
+        // The Null record produces no feature, but the DBF still has 3 rows;
+        // only 2 features come out, one per actual geometry.
+        assert_eq!(features.length(), 2);
+
+        // The second feature is shp record 3, so it carries DBF record 3's
+        // attributes, not record 2's.
+        let feature = Object::from(features.get(1));
+        let properties = Object::from(Reflect::get(&feature, &JsValue::from_str("properties")).unwrap());
+        let id = Reflect::get(&properties, &JsValue::from_str("ID")).unwrap();
+        assert_eq!(id.as_string().as_deref(), Some("C"));
+    }
+
+    fn push_single_char_field_dbf(values: &[(bool, char)]) -> Vec<u8> {
+        // One `C` field "ID" (len 1).
+        let header_length = DBF_HEADER_LENGTH + 32 + 1;
+        let record_length = 1 + 1;
+
+        let mut buf = vec![0u8; DBF_HEADER_LENGTH];
+        buf[4..8].copy_from_slice(&(values.len() as u32).to_le_bytes());
+        buf[8..10].copy_from_slice(&(header_length as u16).to_le_bytes());
+        buf[10..12].copy_from_slice(&(record_length as u16).to_le_bytes());
+
+        let mut field = vec![0u8; 32];
+        field[0..2].copy_from_slice(b"ID");
+        field[11] = b'C';
+        field[16] = 1;
+        buf.extend_from_slice(&field);
+        buf.push(0x0D); // field descriptor terminator
+
+        for &(deleted, value) in values {
+            buf.push(if deleted { 0x2A } else { b' ' });
+            buf.push(value as u8);
+        }
+
+        buf
+    }
+
+    // A deleted DBF record in the middle of the table must not shift every
+    // later shp record onto the wrong attributes: record 1 (0-based) is
+    // deleted, so feature 1 should come back with no properties rather than
+    // record 2's, and feature 2 should still get record 2's.
+    #[wasm_bindgen_test]
+    fn test_parse_with_attributes_skips_deleted_dbf_record_without_shifting_zip() {
+        let mut shp = Vec::new();
+        // header(100) + 3 points * (8 + 20) = 184 bytes = 92 words.
+        push_header(&mut shp, 92, 1);
+        for (x, y) in [(1.0, 1.0), (2.0, 2.0), (3.0, 3.0)] {
+            shp.extend_from_slice(&1i32.to_be_bytes());
+            shp.extend_from_slice(&10i32.to_be_bytes());
+            shp.extend_from_slice(&1i32.to_le_bytes());
+            shp.extend_from_slice(&(x as f64).to_le_bytes());
+            shp.extend_from_slice(&(y as f64).to_le_bytes());
+        }
+
+        let dbf_buf = push_single_char_field_dbf(&[(false, 'A'), (true, 'B'), (false, 'C')]);
+
+        let result = parse_with_attributes(&shp, &dbf_buf).unwrap();
+        let collection = Object::from(result);
+        let features: Array = Reflect::get(&collection, &JsValue::from_str("features")).unwrap().into();
+        assert_eq!(features.length(), 3);
+
+        let get_id = |i: u32| -> JsValue {
+            let feature = Object::from(features.get(i));
+            let properties = Object::from(Reflect::get(&feature, &JsValue::from_str("properties")).unwrap());
+            Reflect::get(&properties, &JsValue::from_str("ID")).unwrap()
+        };
+
+        assert_eq!(get_id(0).as_string().as_deref(), Some("A"));
+        assert_eq!(get_id(1).as_string(), None); // deleted DBF record: empty properties
+        assert_eq!(get_id(2).as_string().as_deref(), Some("C"));
+    }
+
+    // A PolyLine record with 2 real parts must come back as a MultiLineString
+    // with 2 entries, not a single LineString joining the parts with a bogus
+    // segment from the last point of part 1 to the first point of part 2.
+    #[wasm_bindgen_test]
+    fn test_parse_polyline_with_multiple_parts_is_multi_line_string() {
+        let mut shp = Vec::new();
+        // header(100) + record header(8) + shape type(4) + bbox(32) +
+        // num_parts(4) + num_points(4) + parts(2*4) + points(4*16) = 224 bytes = 112 words.
+        push_header(&mut shp, 112, 3);
+
+        shp.extend_from_slice(&1i32.to_be_bytes()); // record number
+        shp.extend_from_slice(&58i32.to_be_bytes()); // content length in words: (4+32+4+4+8+64)/2
+        shp.extend_from_slice(&3i32.to_le_bytes()); // shape type: PolyLine
+        for value in [0.0, 0.0, 11.0, 11.0] {
+            shp.extend_from_slice(&f64::to_le_bytes(value));
+        }
+        shp.extend_from_slice(&2i32.to_le_bytes()); // num parts
+        shp.extend_from_slice(&4i32.to_le_bytes()); // num points
+        shp.extend_from_slice(&0i32.to_le_bytes()); // part 0 starts at point 0
+        shp.extend_from_slice(&2i32.to_le_bytes()); // part 1 starts at point 2
+        for (x, y) in [(0.0f64, 0.0f64), (1.0, 1.0), (10.0, 10.0), (11.0, 11.0)] {
+            shp.extend_from_slice(&x.to_le_bytes());
+            shp.extend_from_slice(&y.to_le_bytes());
+        }
+
+        let result = parse(&shp).unwrap();
+        let collection = Object::from(result);
+        let features: Array = Reflect::get(&collection, &JsValue::from_str("features")).unwrap().into();
+        assert_eq!(features.length(), 1);
+
+        let feature = Object::from(features.get(0));
+        let geometry = Reflect::get(&feature, &JsValue::from_str("geometry")).unwrap();
+        // `convert_polyline`'s output has no "type" wrapper: a MultiLineString
+        // is a flat array of per-part point arrays.
+        let parts: Array = geometry.into();
+        assert_eq!(parts.length(), 2);
+        let first_part: Array = parts.get(0).into();
+        assert_eq!(first_part.length(), 2);
+    }
+}
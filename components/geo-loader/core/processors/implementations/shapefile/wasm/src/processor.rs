@@ -0,0 +1,177 @@
+use wasm_bindgen::prelude::*;
+
+// Visitor-style callbacks driven by `walk_*` as a geometry is traversed, so a
+// single walk can feed any output sink (GeoJSON, MVT, WKT, bounds, ...)
+// without materializing intermediate nested Vecs for each one.
+//
+// All callbacks default to no-ops except `xy`, which every sink needs.
+pub trait GeomProcessor {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<(), JsError>;
+
+    fn point_begin(&mut self, _idx: usize) -> Result<(), JsError> {
+        Ok(())
+    }
+    fn point_end(&mut self, _idx: usize) -> Result<(), JsError> {
+        Ok(())
+    }
+
+    fn multi_point_begin(&mut self, _size: usize, _idx: usize) -> Result<(), JsError> {
+        Ok(())
+    }
+    fn multi_point_end(&mut self, _idx: usize) -> Result<(), JsError> {
+        Ok(())
+    }
+
+    fn linestring_begin(&mut self, _is_ring: bool, _size: usize, _idx: usize) -> Result<(), JsError> {
+        Ok(())
+    }
+    fn linestring_end(&mut self, _is_ring: bool, _idx: usize) -> Result<(), JsError> {
+        Ok(())
+    }
+
+    fn polygon_begin(&mut self, _is_multi: bool, _size: usize, _idx: usize) -> Result<(), JsError> {
+        Ok(())
+    }
+    fn polygon_end(&mut self, _is_multi: bool, _idx: usize) -> Result<(), JsError> {
+        Ok(())
+    }
+}
+
+fn even_length(coordinates: &[f64]) -> Result<(), JsError> {
+    if !coordinates.len().is_multiple_of(2) {
+        return Err(JsError::new("Coordinates array must have even length"));
+    }
+    Ok(())
+}
+
+pub fn walk_point(x: f64, y: f64, processor: &mut dyn GeomProcessor) -> Result<(), JsError> {
+    processor.point_begin(0)?;
+    processor.xy(x, y, 0)?;
+    processor.point_end(0)
+}
+
+pub fn walk_multi_point(coordinates: &[f64], processor: &mut dyn GeomProcessor) -> Result<(), JsError> {
+    even_length(coordinates)?;
+    processor.multi_point_begin(coordinates.len() / 2, 0)?;
+    for (idx, chunk) in coordinates.chunks(2).enumerate() {
+        processor.xy(chunk[0], chunk[1], idx)?;
+    }
+    processor.multi_point_end(0)
+}
+
+pub fn walk_linestring(coordinates: &[f64], is_ring: bool, processor: &mut dyn GeomProcessor) -> Result<(), JsError> {
+    even_length(coordinates)?;
+    processor.linestring_begin(is_ring, coordinates.len() / 2, 0)?;
+    for (idx, chunk) in coordinates.chunks(2).enumerate() {
+        processor.xy(chunk[0], chunk[1], idx)?;
+    }
+    processor.linestring_end(is_ring, 0)
+}
+
+// Walks a polygon's rings in order (exterior first, holes after), delegating
+// the even-length check and chunk(2) iteration shared by every ring.
+pub fn walk_polygon(
+    coordinates: &[f64],
+    ring_sizes: &[usize],
+    is_multi: bool,
+    idx: usize,
+    processor: &mut dyn GeomProcessor,
+) -> Result<(), JsError> {
+    even_length(coordinates)?;
+    processor.polygon_begin(is_multi, ring_sizes.len(), idx)?;
+
+    let mut offset = 0;
+    for &size in ring_sizes {
+        let end = offset + size * 2;
+        if end > coordinates.len() {
+            return Err(JsError::new("Ring size exceeds coordinate buffer"));
+        }
+        walk_linestring(&coordinates[offset..end], true, processor)?;
+        offset = end;
+    }
+
+    processor.polygon_end(is_multi, idx)
+}
+
+// Splits a flat coordinate buffer into ring/part slices by `ring_sizes`,
+// or treats the whole buffer as a single part when `ring_sizes` is empty
+// (the same simplification `ShapefileProcessor::process_geometry` uses).
+pub fn ring_slices<'a>(coordinates: &'a [f64], ring_sizes: &[usize]) -> Result<Vec<&'a [f64]>, JsError> {
+    if ring_sizes.is_empty() {
+        return Ok(vec![coordinates]);
+    }
+
+    let mut parts = Vec::with_capacity(ring_sizes.len());
+    let mut offset = 0;
+    for &size in ring_sizes {
+        let end = offset + size * 2;
+        if end > coordinates.len() {
+            return Err(JsError::new("Ring size exceeds coordinate buffer"));
+        }
+        parts.push(&coordinates[offset..end]);
+        offset = end;
+    }
+    Ok(parts)
+}
+
+// Collects flat coordinate pairs and, when the walked geometry has rings,
+// the boundary between them. Shared by the GeoJSON and WKT writers, whose
+// output shapes differ only in final formatting.
+#[derive(Default)]
+pub struct PairCollector {
+    pairs: Vec<[f64; 2]>,
+    rings: Vec<Vec<[f64; 2]>>,
+}
+
+impl PairCollector {
+    pub fn new() -> Self {
+        PairCollector::default()
+    }
+
+    pub fn into_pairs(self) -> Vec<[f64; 2]> {
+        self.pairs
+    }
+
+    pub fn into_rings(self) -> Vec<Vec<[f64; 2]>> {
+        self.rings
+    }
+}
+
+impl GeomProcessor for PairCollector {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<(), JsError> {
+        self.pairs.push([x, y]);
+        Ok(())
+    }
+
+    fn linestring_end(&mut self, _is_ring: bool, _idx: usize) -> Result<(), JsError> {
+        self.rings.push(std::mem::take(&mut self.pairs));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_walk_multi_point_collects_pairs() {
+        let mut collector = PairCollector::new();
+        walk_multi_point(&[0.0, 0.0, 1.0, 1.0], &mut collector).unwrap();
+        assert_eq!(collector.into_pairs(), vec![[0.0, 0.0], [1.0, 1.0]]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_walk_polygon_collects_rings() {
+        let mut collector = PairCollector::new();
+        let coords = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0];
+        walk_polygon(&coords, &[4], false, 0, &mut collector).unwrap();
+        assert_eq!(collector.into_rings().len(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_walk_rejects_odd_length() {
+        let mut collector = PairCollector::new();
+        assert!(walk_multi_point(&[0.0, 0.0, 1.0], &mut collector).is_err());
+    }
+}
@@ -143,6 +143,38 @@ pub fn validate_point_coordinates(
     Ok(())
 }
 
+// Validate point coordinates including Z (elevation) and M (measure)
+#[wasm_bindgen]
+pub fn validate_point_coordinates_z(
+    x: f64,
+    y: f64,
+    z: f64,
+    m: f64,
+    part_index: i32,
+    point_index: i32,
+) -> Result<(), JsError> {
+    validate_point_coordinates(x, y, part_index, point_index)?;
+
+    if !z.is_finite() {
+        return Err(JsError::new(&format!(
+            "Invalid shapefile: non-finite Z value ({}) at part {}, point {}",
+            z, part_index, point_index
+        )));
+    }
+
+    // NaN means "no measure" (the crate's own convention for an absent M) and
+    // a value below -1e38 means "no data" per the shapefile spec itself;
+    // neither is range-checked. Only actual +/-infinity is rejected.
+    if m.is_infinite() {
+        return Err(JsError::new(&format!(
+            "Invalid shapefile: invalid M value ({}) at part {}, point {}",
+            m, part_index, point_index
+        )));
+    }
+
+    Ok(())
+}
+
 // Validate number of parts and points for complex shapes
 #[wasm_bindgen]
 pub fn validate_parts_and_points(
@@ -233,4 +265,14 @@ mod tests {
         assert!(!validate_shape_type(0).unwrap()); // Null shape
         assert!(validate_shape_type(999).is_err()); // Invalid
     }
+
+    #[wasm_bindgen_test]
+    fn test_validate_point_coordinates_z() {
+        assert!(validate_point_coordinates_z(0.0, 0.0, 10.0, 5.0, 0, 0).is_ok());
+        assert!(validate_point_coordinates_z(0.0, 0.0, f64::NAN, 5.0, 0, 0).is_err());
+        // NaN M (no measure) and the shapefile "no data" sentinel are both accepted.
+        assert!(validate_point_coordinates_z(0.0, 0.0, 10.0, f64::NAN, 0, 0).is_ok());
+        assert!(validate_point_coordinates_z(0.0, 0.0, 10.0, -1e39, 0, 0).is_ok());
+        assert!(validate_point_coordinates_z(0.0, 0.0, 10.0, f64::INFINITY, 0, 0).is_err());
+    }
 }
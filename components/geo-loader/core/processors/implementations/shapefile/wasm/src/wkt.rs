@@ -0,0 +1,313 @@
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::geometry::is_clockwise;
+use crate::processor::{self, ring_slices, PairCollector};
+
+// The flat `&[f64]` + `ring_sizes` representation the rest of the crate uses,
+// returned to JS so callers can feed it straight into `convert_*`/`process_geometry`.
+#[derive(Serialize)]
+pub struct ParsedWkt {
+    shape_type: u32,
+    coordinates: Vec<f64>,
+    ring_sizes: Vec<usize>,
+}
+
+fn fmt_num(n: f64) -> String {
+    n.to_string()
+}
+
+fn fmt_xy(x: f64, y: f64) -> String {
+    format!("{} {}", fmt_num(x), fmt_num(y))
+}
+
+fn fmt_ring(pairs: &[[f64; 2]]) -> String {
+    pairs
+        .iter()
+        .map(|[x, y]| fmt_xy(*x, *y))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn collect_ring(part: &[f64]) -> Result<Vec<[f64; 2]>, JsError> {
+    let mut collector = PairCollector::new();
+    processor::walk_linestring(part, true, &mut collector)?;
+    Ok(collector.into_rings().pop().unwrap_or_default())
+}
+
+// Serialize a shapefile geometry (in the crate's flat coordinate + ring-size
+// representation) to Well-Known Text.
+#[wasm_bindgen]
+pub fn geometry_to_wkt(shape_type: u32, coordinates: &[f64], ring_sizes: &[usize]) -> Result<String, JsError> {
+    match shape_type {
+        1 => {
+            // Point
+            if coordinates.len() != 2 {
+                return Err(JsError::new("Point must have exactly 2 coordinates"));
+            }
+            Ok(format!("POINT ({})", fmt_xy(coordinates[0], coordinates[1])))
+        }
+        8 => {
+            // MultiPoint
+            let mut collector = PairCollector::new();
+            processor::walk_multi_point(coordinates, &mut collector)?;
+            let points = collector.into_pairs();
+            Ok(format!("MULTIPOINT ({})", fmt_ring(&points)))
+        }
+        3 => {
+            // PolyLine -> LineString or MultiLineString
+            let parts = ring_slices(coordinates, ring_sizes)?;
+            if parts.len() <= 1 {
+                let line = collect_ring(parts[0])?;
+                Ok(format!("LINESTRING ({})", fmt_ring(&line)))
+            } else {
+                let lines = parts
+                    .iter()
+                    .map(|part| collect_ring(part).map(|line| format!("({})", fmt_ring(&line))))
+                    .collect::<Result<Vec<String>, JsError>>()?;
+                Ok(format!("MULTILINESTRING ({})", lines.join(", ")))
+            }
+        }
+        5 => {
+            // Polygon -> Polygon or MultiPolygon, grouping rings into polygons the
+            // same way `convert_polygon` does: a clockwise ring starts a new
+            // polygon, counter-clockwise rings are holes in the current one.
+            let parts = ring_slices(coordinates, ring_sizes)?;
+            let mut polygons: Vec<Vec<Vec<[f64; 2]>>> = Vec::new();
+            let mut current: Vec<Vec<[f64; 2]>> = Vec::new();
+
+            for part in parts {
+                let ring = collect_ring(part)?;
+                if is_clockwise(part)? && !current.is_empty() {
+                    polygons.push(std::mem::take(&mut current));
+                }
+                current.push(ring);
+            }
+            if !current.is_empty() {
+                polygons.push(current);
+            }
+
+            let fmt_polygon = |rings: &[Vec<[f64; 2]>]| -> String {
+                let ring_strs: Vec<String> = rings.iter().map(|r| format!("({})", fmt_ring(r))).collect();
+                format!("({})", ring_strs.join(", "))
+            };
+
+            if polygons.len() == 1 {
+                Ok(format!("POLYGON {}", fmt_polygon(&polygons[0])))
+            } else {
+                let poly_strs: Vec<String> = polygons.iter().map(|p| fmt_polygon(p)).collect();
+                Ok(format!("MULTIPOLYGON ({})", poly_strs.join(", ")))
+            }
+        }
+        _ => Err(JsError::new("Unsupported shape type for WKT encoding")),
+    }
+}
+
+const TAGS: &[(&str, u32)] = &[
+    ("MULTIPOLYGON", 5),
+    ("MULTILINESTRING", 3),
+    ("MULTIPOINT", 8),
+    ("POLYGON", 5),
+    ("LINESTRING", 3),
+    ("POINT", 1),
+];
+
+fn parse_tag(text: &str) -> Result<(u32, &str), JsError> {
+    let trimmed = text.trim();
+    let upper = trimmed.to_uppercase();
+
+    for &(tag, shape_type) in TAGS {
+        if let Some(rest) = upper.strip_prefix(tag) {
+            if rest.chars().next().map_or(true, |c| c.is_whitespace() || c == '(') {
+                let mut body = trimmed[tag.len()..].trim_start();
+                // Skip an optional Z/M/ZM dimensionality marker before the coordinate list.
+                while let Some(c) = body.chars().next() {
+                    if c.is_alphabetic() {
+                        body = &body[c.len_utf8()..];
+                    } else {
+                        break;
+                    }
+                }
+                return Ok((shape_type, body.trim_start()));
+            }
+        }
+    }
+    Err(JsError::new(&format!("Unrecognized WKT geometry type in '{}'", trimmed)))
+}
+
+fn skip_ws(chars: &[char], i: &mut usize) {
+    while *i < chars.len() && chars[*i].is_whitespace() {
+        *i += 1;
+    }
+}
+
+fn parse_number(chars: &[char], i: &mut usize) -> Result<f64, JsError> {
+    let start = *i;
+    if *i < chars.len() && (chars[*i] == '-' || chars[*i] == '+') {
+        *i += 1;
+    }
+    while *i < chars.len() && (chars[*i].is_ascii_digit() || chars[*i] == '.') {
+        *i += 1;
+    }
+    if *i < chars.len() && (chars[*i] == 'e' || chars[*i] == 'E') {
+        let save = *i;
+        *i += 1;
+        if *i < chars.len() && (chars[*i] == '-' || chars[*i] == '+') {
+            *i += 1;
+        }
+        if *i < chars.len() && chars[*i].is_ascii_digit() {
+            while *i < chars.len() && chars[*i].is_ascii_digit() {
+                *i += 1;
+            }
+        } else {
+            *i = save; // not actually an exponent
+        }
+    }
+
+    let text: String = chars[start..*i].iter().collect();
+    text.trim()
+        .parse::<f64>()
+        .map_err(|_| JsError::new(&format!("Invalid number in WKT: '{}'", text)))
+}
+
+// Parses a single `(x y, x y, ...)` point list, appending each pair to
+// `coordinates` and returning how many points it contained (extra Z/M
+// ordinates are parsed but discarded, since the crate's representation is 2D).
+fn parse_point_list(chars: &[char], i: &mut usize, coordinates: &mut Vec<f64>) -> Result<usize, JsError> {
+    *i += 1; // consume '('
+    let mut count = 0;
+    loop {
+        skip_ws(chars, i);
+        let x = parse_number(chars, i)?;
+        skip_ws(chars, i);
+        let y = parse_number(chars, i)?;
+        coordinates.push(x);
+        coordinates.push(y);
+        count += 1;
+
+        skip_ws(chars, i);
+        while *i < chars.len() && chars[*i] != ',' && chars[*i] != ')' {
+            parse_number(chars, i)?;
+            skip_ws(chars, i);
+        }
+
+        if *i < chars.len() && chars[*i] == ',' {
+            *i += 1;
+            continue;
+        }
+        break;
+    }
+
+    skip_ws(chars, i);
+    if *i >= chars.len() || chars[*i] != ')' {
+        return Err(JsError::new("Malformed WKT: expected ')'"));
+    }
+    *i += 1;
+    Ok(count)
+}
+
+// Parses a parenthesized group that is either a point list or a nested list
+// of groups (rings, parts, or polygons), recursing until it bottoms out.
+fn parse_group(
+    chars: &[char],
+    i: &mut usize,
+    coordinates: &mut Vec<f64>,
+    ring_sizes: &mut Vec<usize>,
+) -> Result<(), JsError> {
+    skip_ws(chars, i);
+    if *i >= chars.len() || chars[*i] != '(' {
+        return Err(JsError::new("Malformed WKT: expected '('"));
+    }
+
+    let mut peek = *i + 1;
+    skip_ws(chars, &mut peek);
+    let opens_point_list = peek < chars.len()
+        && (chars[peek].is_ascii_digit() || chars[peek] == '-' || chars[peek] == '+' || chars[peek] == '.');
+
+    if opens_point_list {
+        let count = parse_point_list(chars, i, coordinates)?;
+        ring_sizes.push(count);
+        return Ok(());
+    }
+
+    *i += 1; // consume '('
+    loop {
+        parse_group(chars, i, coordinates, ring_sizes)?;
+        skip_ws(chars, i);
+        if *i < chars.len() && chars[*i] == ',' {
+            *i += 1;
+            continue;
+        }
+        break;
+    }
+
+    skip_ws(chars, i);
+    if *i >= chars.len() || chars[*i] != ')' {
+        return Err(JsError::new("Malformed WKT: expected ')'"));
+    }
+    *i += 1;
+    Ok(())
+}
+
+fn parse_coord_lists(body: &str) -> Result<(Vec<f64>, Vec<usize>), JsError> {
+    let chars: Vec<char> = body.chars().collect();
+    let mut i = 0;
+    let mut coordinates = Vec::new();
+    let mut ring_sizes = Vec::new();
+    parse_group(&chars, &mut i, &mut coordinates, &mut ring_sizes)?;
+    Ok((coordinates, ring_sizes))
+}
+
+// Parses Well-Known Text into the crate's flat coordinate + ring-size
+// representation, so callers can hand the result straight to `convert_*`.
+#[wasm_bindgen]
+pub fn wkt_to_geometry(text: &str) -> Result<JsValue, JsError> {
+    let (shape_type, body) = parse_tag(text)?;
+    let (coordinates, ring_sizes) = parse_coord_lists(body)?;
+    let parsed = ParsedWkt {
+        shape_type,
+        coordinates,
+        ring_sizes,
+    };
+    serde_wasm_bindgen::to_value(&parsed).map_err(|e| JsError::new(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_point_to_wkt() {
+        let wkt = geometry_to_wkt(1, &[1.0, 2.0], &[]).unwrap();
+        assert_eq!(wkt, "POINT (1 2)");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_polygon_to_wkt() {
+        let coords = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0];
+        let wkt = geometry_to_wkt(5, &coords, &[4]).unwrap();
+        assert!(wkt.starts_with("POLYGON"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wkt_roundtrip_point() {
+        let parsed = wkt_to_geometry("POINT (1 2)");
+        assert!(parsed.is_ok());
+
+        let (shape_type, body) = parse_tag("POINT (1 2)").unwrap();
+        let (coordinates, ring_sizes) = parse_coord_lists(body).unwrap();
+        assert_eq!(geometry_to_wkt(shape_type, &coordinates, &ring_sizes).unwrap(), "POINT (1 2)");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wkt_to_geometry_polygon() {
+        let wkt = "POLYGON ((0 0, 1 0, 1 1, 0 0))";
+        assert!(wkt_to_geometry(wkt).is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wkt_to_geometry_rejects_unknown_tag() {
+        assert!(wkt_to_geometry("CIRCLE (0 0, 1)").is_err());
+    }
+}